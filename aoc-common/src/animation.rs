@@ -0,0 +1,60 @@
+use std::fmt;
+use std::ops::ControlFlow;
+
+use crate::Grid;
+
+/// A picture of the state of a simulation at some step, with a caption describing that step.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub cells: Grid<char>,
+}
+
+impl Frame {
+    pub fn new(caption: impl Into<String>, cells: Grid<char>) -> Self {
+        Frame {
+            caption: caption.into(),
+            cells,
+        }
+    }
+}
+
+/// The caption in the first line, and the cells below it.
+impl fmt::Display for Frame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.caption)?;
+        write!(f, "{}", self.cells)
+    }
+}
+
+/**
+ * Where the frames of a simulation go, e.g. a player in the terminal or files on disk.
+ *
+ * Simulations hand over each frame as soon as it's ready and wait for `show` to return, so a sink
+ * decides the pace at which the simulation runs. Returning `ControlFlow::Break` stops it.
+ */
+pub trait FrameSink {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()>;
+}
+
+/// Keeps every frame in memory, mostly useful for tests.
+impl FrameSink for Vec<Frame> {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()> {
+        self.push(frame);
+        ControlFlow::Continue(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn displays_caption_above_cells() {
+        let mut cells = Grid::new(3, 2, '.');
+        cells[(1, 1)] = '#';
+        let frame = Frame::new("Step 1", cells);
+
+        assert_eq!(frame.to_string(), "Step 1\n...\n.#.");
+    }
+}
@@ -0,0 +1,106 @@
+use std::convert::Infallible;
+use std::fmt;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+/**
+ * The answer to one part of a day's challenge.
+ *
+ * Most answers are numbers, but some days expect a word (e.g. the crates on top of each stack in
+ * day 5) or produce a picture that spans multiple lines (e.g. the CRT in day 10). Keeping the
+ * distinction lets answers be compared with each other, and printed in a way that makes sense for
+ * each kind.
+ *
+ * Serialized, an answer is either a number or a string. Strings that span multiple lines come back
+ * as renderings, so the variant survives a round trip.
+ */
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(from = "RawAnswer", into = "RawAnswer")]
+pub enum Answer {
+    Integer(i64),
+    Text(String),
+    Rendering(String),
+}
+
+impl Answer {
+    pub fn is_rendering(&self) -> bool {
+        matches!(self, Answer::Rendering(_))
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(number) => write!(f, "{}", number),
+            Answer::Text(text) | Answer::Rendering(text) => write!(f, "{}", text),
+        }
+    }
+}
+
+impl FromStr for Answer {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.parse() {
+            Ok(number) => Ok(Answer::Integer(number)),
+            Err(_) => Ok(RawAnswer::Text(s.into()).into()),
+        }
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(value: usize) -> Self {
+        Answer::Integer(i64::try_from(value).expect("Answer does not fit in an i64"))
+    }
+}
+
+impl From<isize> for Answer {
+    fn from(value: isize) -> Self {
+        Answer::Integer(value as i64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(value: i64) -> Self {
+        Answer::Integer(value)
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.into())
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum RawAnswer {
+    Integer(i64),
+    Text(String),
+}
+
+impl From<RawAnswer> for Answer {
+    fn from(raw: RawAnswer) -> Self {
+        match raw {
+            RawAnswer::Integer(number) => Answer::Integer(number),
+            RawAnswer::Text(text) if text.contains('\n') => Answer::Rendering(text),
+            RawAnswer::Text(text) => Answer::Text(text),
+        }
+    }
+}
+
+impl From<Answer> for RawAnswer {
+    fn from(answer: Answer) -> Self {
+        match answer {
+            Answer::Integer(number) => RawAnswer::Integer(number),
+            Answer::Text(text) | Answer::Rendering(text) => RawAnswer::Text(text),
+        }
+    }
+}
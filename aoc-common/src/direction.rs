@@ -0,0 +1,71 @@
+use crate::Point;
+
+/// One of the 4 orthogonal directions, with `y` growing downwards (so `Up` is negative `y`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Every direction, clockwise from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The difference between a point and the next one in this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Direction::Up => Point::new(0, -1),
+            Direction::Right => Point::new(1, 0),
+            Direction::Down => Point::new(0, 1),
+            Direction::Left => Point::new(-1, 0),
+        }
+    }
+
+    /// The direction after a 90° clockwise turn.
+    pub fn turn_right(self) -> Direction {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    /// The direction after a 90° counterclockwise turn.
+    pub fn turn_left(self) -> Direction {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Direction {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turns_around() {
+        assert_eq!(Direction::Up.turn_right(), Direction::Right);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Down.opposite(), Direction::Up);
+    }
+
+    #[test]
+    fn deltas_match_turns() {
+        for direction in Direction::ALL {
+            let delta = direction.delta();
+
+            assert_eq!(direction.opposite().delta(), -delta);
+            // Rotating (x, y) 90° clockwise with y growing downwards gives (-y, x)
+            assert_eq!(
+                direction.turn_right().delta(),
+                Point::new(-delta.y, delta.x)
+            );
+        }
+    }
+}
@@ -0,0 +1,346 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::{Direction, Input, ParseError, ParseResult, Point};
+
+/**
+ * A dense, rectangular 2D grid of cells, stored row by row.
+ *
+ * Positions are `(x, y)` pairs, where `x` is the column and `y` is the row, starting from the top
+ * left corner. Indexing with a position outside of the grid panics, `get` is the bounds-checked
+ * alternative. Iterators that walk over positions (neighbours, rows, columns and rays) only ever
+ * yield positions that are inside the grid.
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Offsets to the 8 neighbours of a cell, including diagonals, clockwise from the top left.
+const ALL_AROUND: [Point; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(1, 0),
+    Point::new(1, 1),
+    Point::new(0, 1),
+    Point::new(-1, 1),
+    Point::new(-1, 0),
+];
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Grid with each cell set to what `cell` returns for its position.
+    pub fn from_fn(width: usize, height: usize, cell: impl FnMut((usize, usize)) -> T) -> Self {
+        let mut grid = Grid {
+            width,
+            height,
+            cells: vec![],
+        };
+        grid.cells = grid.positions().map(cell).collect();
+        grid
+    }
+
+    /**
+     * Parses a grid with a cell for each character of the input, turning each of them into a cell
+     * with `cell`. Characters for which it fails are pointed at with the message it returns, and
+     * lines that don't have the same length as the first one are invalid too.
+     */
+    pub fn parse(
+        input: &Input,
+        mut cell: impl FnMut(char) -> Result<T, String>,
+    ) -> ParseResult<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for (number, line) in input.numbered_lines() {
+            let length = line.chars().count();
+
+            if *width.get_or_insert(length) != length {
+                let error = ParseError::new(
+                    format!(
+                        "Expected {} cells like the first line",
+                        width.unwrap_or_default()
+                    ),
+                    line,
+                );
+                return Err(input.locate(error, number));
+            }
+
+            for (column, char) in line.chars().enumerate() {
+                let value = cell(char).map_err(|message| {
+                    let error = ParseError::with_columns(message, line, column..column + 1);
+                    input.locate(error, number)
+                })?;
+                cells.push(value);
+            }
+
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or_default(),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (x, y): (usize, usize)) -> bool {
+        x < self.width && y < self.height
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.contains(position)
+            .then(|| &self.cells[self.index_of(position)])
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        if self.contains(position) {
+            let index = self.index_of(position);
+            Some(&mut self.cells[index])
+        } else {
+            None
+        }
+    }
+
+    /// Adds a row at the bottom of the grid. It must have as many cells as the grid is wide.
+    pub fn push_row(&mut self, row: impl IntoIterator<Item = T>) {
+        let length = self.cells.len();
+        self.cells.extend(row);
+        assert_eq!(
+            self.cells.len() - length,
+            self.width,
+            "Rows must have as many cells as the grid is wide"
+        );
+        self.height += 1;
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| (x, y)))
+    }
+
+    /// Every cell in the grid along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// Position of the first cell (row by row) that matches `predicate`.
+    pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<(usize, usize)> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(position, _)| position)
+    }
+
+    /// New grid of the same size, with `f` applied to every cell.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.cells.iter().map(f).collect(),
+        }
+    }
+
+    /// The position that is `offset` away from `position`, if it's inside the grid.
+    pub fn step(&self, (x, y): (usize, usize), offset: Point) -> Option<(usize, usize)> {
+        let position = (
+            x.checked_add_signed(offset.x)?,
+            y.checked_add_signed(offset.y)?,
+        );
+        self.contains(position).then_some(position)
+    }
+
+    /// Positions of the (up to) 4 cells that share a side with `position`.
+    pub fn neighbours(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction.delta()))
+    }
+
+    /// Positions of the (up to) 8 cells that share a side or a corner with `position`.
+    pub fn neighbours_with_diagonals(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        ALL_AROUND
+            .into_iter()
+            .filter_map(move |offset| self.step(position, offset))
+    }
+
+    /// Positions in row `y`, from left to right.
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = (usize, usize)> {
+        (0..self.width).map(move |x| (x, y))
+    }
+
+    /// Positions in column `x`, from top to bottom.
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = (usize, usize)> {
+        (0..self.height).map(move |y| (x, y))
+    }
+
+    /// Positions going from `position` (not included) in steps of `offset`, until the edge.
+    pub fn ray(
+        &self,
+        position: (usize, usize),
+        offset: Point,
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let mut current = position;
+
+        std::iter::from_fn(move || {
+            current = self.step(current, offset)?;
+            Some(current)
+        })
+    }
+
+    /// Rows of cells, from top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        // `chunks_exact` panics with a chunk size of 0, which empty grids would have
+        self.cells.chunks_exact(self.width.max(1))
+    }
+
+    fn index_of(&self, (x, y): (usize, usize)) -> usize {
+        y * self.width + x
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &Self::Output {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut Self::Output {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside of the grid", position))
+    }
+}
+
+/// Each row in its own line, with every cell next to each other.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.rows().enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digit(char: char) -> Result<u32, String> {
+        char.to_digit(10)
+            .ok_or_else(|| format!("Invalid digit {}", char))
+    }
+
+    fn digits(text: &str) -> Grid<u32> {
+        Grid::parse(&Input::from_text(text), digit).unwrap()
+    }
+
+    #[test]
+    fn parses_and_displays_characters() {
+        let grid = digits("123\n456\n");
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[(2, 0)], 3);
+        assert_eq!(grid[(0, 1)], 4);
+        assert_eq!(grid.get((3, 0)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+    }
+
+    #[test]
+    fn rejects_invalid_characters_and_ragged_lines() {
+        let error = Grid::parse(&Input::from_text("123\n4x6\n"), digit).unwrap_err();
+        assert_eq!(error.message(), "Invalid digit x");
+        assert_eq!((error.line(), error.column()), (Some(2), 2));
+
+        let error = Grid::parse(&Input::from_text("123\n45\n"), digit).unwrap_err();
+        assert_eq!(error.line(), Some(2));
+    }
+
+    #[test]
+    fn finds_neighbours_inside_the_grid() {
+        let grid = digits("123\n456\n789\n");
+
+        let corner: Vec<_> = grid.neighbours((0, 0)).collect();
+        assert_eq!(corner, [(1, 0), (0, 1)]);
+
+        let center: Vec<u32> = grid
+            .neighbours_with_diagonals((1, 1))
+            .map(|position| grid[position])
+            .collect();
+        assert_eq!(center, [1, 2, 3, 6, 9, 8, 7, 4]);
+    }
+
+    #[test]
+    fn walks_rows_columns_and_rays() {
+        let grid = digits("123\n456\n789\n");
+        let values = |positions: Vec<(usize, usize)>| -> Vec<u32> {
+            positions
+                .into_iter()
+                .map(|position| grid[position])
+                .collect()
+        };
+
+        assert_eq!(values(grid.row(1).rev().collect()), [6, 5, 4]);
+        assert_eq!(values(grid.column(2).collect()), [3, 6, 9]);
+        assert_eq!(values(grid.ray((0, 0), Point::new(1, 1)).collect()), [5, 9]);
+        assert_eq!(
+            values(grid.ray((1, 2), Direction::Up.delta()).collect()),
+            [5, 2]
+        );
+    }
+
+    #[test]
+    fn builds_cells_from_positions() {
+        let grid = Grid::from_fn(3, 2, |(x, y)| x + y * 10);
+        assert_eq!(grid.to_string(), "012\n101112");
+    }
+
+    #[test]
+    fn grows_downwards() {
+        let mut grid = Grid::new(2, 0, '.');
+        grid.push_row(['#', '.']);
+        grid[(1, 0)] = '#';
+        grid.push_row(['.', '#']);
+
+        assert_eq!(grid.to_string(), "##\n.#");
+        assert_eq!(grid.position(|&cell| cell == '.'), Some((0, 1)));
+    }
+}
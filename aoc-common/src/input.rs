@@ -0,0 +1,195 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::iter;
+use std::path::Path;
+use std::str::{FromStr, Lines};
+
+use anyhow::{bail, Context};
+
+use crate::{ParseError, ParseResult};
+
+/**
+ * The puzzle input for a day, fully loaded in memory.
+ *
+ * Inputs are small enough that reading them upfront is simpler than streaming them, and it lets
+ * the same type be built from a file, from stdin or from a string in the code itself. The `name`
+ * is only used to describe where the input came from (e.g. the file path) in messages.
+ *
+ * Line endings are normalized to `\n` when the input is loaded, so files saved with CRLF endings
+ * parse the same as any other.
+ */
+#[derive(Debug, Clone)]
+pub struct Input {
+    name: String,
+    text: String,
+}
+
+impl Input {
+    /// Reads the whole file at `path`.
+    pub fn from_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read file {}", path.display()))?;
+
+        Ok(Input::new(path.display().to_string(), text))
+    }
+
+    /// Reads stdin until it's closed.
+    pub fn from_stdin() -> anyhow::Result<Self> {
+        let mut text = String::new();
+        io::stdin()
+            .read_to_string(&mut text)
+            .context("Unable to read stdin")?;
+
+        Ok(Input::new("<stdin>".into(), text))
+    }
+
+    /// Wraps a string that is already in memory.
+    pub fn from_text(text: impl Into<String>) -> Self {
+        Input::new("<text>".into(), text.into())
+    }
+
+    fn new(name: String, text: String) -> Self {
+        let text = if text.contains('\r') {
+            text.replace("\r\n", "\n")
+        } else {
+            text
+        };
+
+        Input { name, text }
+    }
+
+    /**
+     * Copy of the input without whitespace at the end of each line, or blank lines at the end of
+     * the input, for days where a stray space would otherwise be taken as part of the data.
+     */
+    pub fn trim_trailing_whitespace(&self) -> Input {
+        let mut text: String = self
+            .lines()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+            .trim_end()
+            .into();
+        if !text.is_empty() {
+            text.push('\n');
+        }
+
+        Input {
+            name: self.name.clone(),
+            text,
+        }
+    }
+
+    /// Reads the file at `path`, or stdin if `path` is `-`.
+    pub fn load(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        if path.as_ref() == Path::new("-") {
+            Input::from_stdin()
+        } else {
+            Input::from_path(path)
+        }
+    }
+
+    /// Loads the input given as the first command line argument (see `Input::load`).
+    pub fn from_args() -> anyhow::Result<Self> {
+        match env::args().nth(1) {
+            Some(path) => Input::load(path),
+            None => bail!("Missing file path"),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn lines(&self) -> Lines<'_> {
+        self.text.lines()
+    }
+
+    /// Lines along with their 1-based number, to report where errors are with `ParseError::at_line`.
+    pub fn numbered_lines(&self) -> impl Iterator<Item = (usize, &str)> {
+        self.lines()
+            .enumerate()
+            .map(|(index, line)| (index + 1, line))
+    }
+
+    /**
+     * Groups of consecutive lines, separated by blank lines (which may contain whitespace), along
+     * with their line numbers. Any amount of blank lines between groups, or around all of them, is
+     * ignored, so there are no empty groups.
+     */
+    pub fn paragraphs(&self) -> impl Iterator<Item = Vec<(usize, &str)>> {
+        let mut lines = self.numbered_lines().peekable();
+
+        iter::from_fn(move || {
+            while lines.next_if(|(_, line)| is_blank(line)).is_some() {}
+
+            let paragraph: Vec<(usize, &str)> =
+                iter::from_fn(|| lines.next_if(|(_, line)| !is_blank(line))).collect();
+
+            (!paragraph.is_empty()).then_some(paragraph)
+        })
+    }
+
+    /// Parses each line on its own, with errors pointing at the line that failed.
+    pub fn parse_lines<T: FromStr<Err = ParseError>>(&self) -> ParseResult<Vec<T>> {
+        self.parse_lines_with(str::parse)
+    }
+
+    /// Like `parse_lines`, for lines that are parsed with something other than `FromStr`.
+    pub fn parse_lines_with<T>(
+        &self,
+        mut parse: impl FnMut(&str) -> ParseResult<T>,
+    ) -> ParseResult<Vec<T>> {
+        self.numbered_lines()
+            .map(|(number, line)| parse(line).map_err(|error| self.locate(error, number)))
+            .collect()
+    }
+
+    /// Adds the line number and the name of this input to an error about one of its lines.
+    pub fn locate(&self, error: ParseError, line: usize) -> ParseError {
+        error.at_line(line).in_file(&self.name)
+    }
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_line_endings() {
+        let input = Input::from_text("1000\r\n2000\r\n\r\n3000\r\n");
+        assert_eq!(input.text(), "1000\n2000\n\n3000\n");
+    }
+
+    #[test]
+    fn trims_trailing_whitespace() {
+        let input = Input::from_text("<<>> \n\t\n\n").trim_trailing_whitespace();
+        assert_eq!(input.text(), "<<>>\n");
+        assert_eq!(input.lines().collect::<Vec<_>>(), ["<<>>"]);
+    }
+
+    #[test]
+    fn groups_lines_in_paragraphs() {
+        let input = Input::from_text("\n1000\n2000\n\n3000\n  \n\n4000\n\n");
+        let paragraphs: Vec<Vec<(usize, &str)>> = input.paragraphs().collect();
+
+        assert_eq!(
+            paragraphs,
+            [
+                vec![(2, "1000"), (3, "2000")],
+                vec![(5, "3000")],
+                vec![(8, "4000")],
+            ]
+        );
+    }
+}
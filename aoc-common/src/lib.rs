@@ -0,0 +1,31 @@
+/*!
+ * Support code shared by every day of the Advent of Code 2022 solutions.
+ *
+ * Each day used to carry its own copy of the input loading code. Anything that is needed by more
+ * than one day lives here instead, so that fixes only have to be made once.
+ */
+mod animation;
+mod answer;
+mod direction;
+mod grid;
+mod input;
+mod logging;
+mod manifest;
+pub mod ocr;
+mod parse_error;
+mod point;
+pub mod search;
+mod solution;
+#[doc(hidden)]
+pub mod testing;
+
+pub use animation::{Frame, FrameSink};
+pub use answer::Answer;
+pub use direction::Direction;
+pub use grid::Grid;
+pub use input::Input;
+pub use logging::init_logging;
+pub use manifest::{AnswerManifest, Expected, InputFile};
+pub use parse_error::{parse_number, ParseError, ParseResult};
+pub use point::Point;
+pub use solution::{print_answer, print_unsolved, run, Part, Solution, Unsolved};
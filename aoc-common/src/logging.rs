@@ -0,0 +1,23 @@
+use anyhow::{anyhow, Context};
+use tracing_subscriber::EnvFilter;
+
+/**
+ * Sends logs to stderr, so they don't get mixed with the answers.
+ *
+ * `filter` is a filter like `debug` or `day14=debug,aoc_common=off`. Without one, the `RUST_LOG`
+ * environment variable is used instead, and only warnings are logged when that isn't set either.
+ */
+pub fn init_logging(filter: Option<&str>) -> anyhow::Result<()> {
+    let filter = match filter {
+        Some(filter) => {
+            EnvFilter::try_new(filter).with_context(|| format!("Invalid log filter {}", filter))?
+        }
+        None => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+    };
+
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(std::io::stderr)
+        .try_init()
+        .map_err(|error| anyhow!("Unable to set up logging: {}", error))
+}
@@ -0,0 +1,142 @@
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+
+use anyhow::Context;
+use serde::{Deserialize, Serialize};
+
+use crate::{Answer, Part};
+
+/**
+ * Known correct answers for a day, as recorded in the `answers.toml` file of its crate. Parts that
+ * haven't been solved (or inputs that don't apply to the day) are just left out of the file.
+ *
+ * ```toml
+ * [sample]
+ * part1 = 24000
+ * part2 = 45000
+ *
+ * [input]
+ * part1 = 70698
+ * ```
+ */
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnswerManifest {
+    #[serde(default)]
+    pub sample: Expected,
+    #[serde(default)]
+    pub input: Expected,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    pub part1: Option<Answer>,
+    pub part2: Option<Answer>,
+}
+
+impl AnswerManifest {
+    pub const FILE_NAME: &'static str = "answers.toml";
+
+    pub fn from_path(path: impl AsRef<Path>) -> anyhow::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read file {}", path.display()))?;
+        text.parse()
+            .with_context(|| format!("Invalid answers in {}", path.display()))
+    }
+
+    /// Reads the manifest of the crate in `directory`.
+    pub fn from_directory(directory: impl AsRef<Path>) -> anyhow::Result<Self> {
+        AnswerManifest::from_path(directory.as_ref().join(AnswerManifest::FILE_NAME))
+    }
+
+    pub fn expected(&self, file: InputFile) -> &Expected {
+        match file {
+            InputFile::Sample => &self.sample,
+            InputFile::Real => &self.input,
+        }
+    }
+}
+
+impl FromStr for AnswerManifest {
+    type Err = anyhow::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(toml::from_str(s)?)
+    }
+}
+
+impl Expected {
+    pub fn get(&self, part: Part) -> Option<&Answer> {
+        match part {
+            Part::One => self.part1.as_ref(),
+            Part::Two => self.part2.as_ref(),
+        }
+    }
+}
+
+/// The input files every day ships with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum InputFile {
+    Sample,
+    Real,
+}
+
+impl InputFile {
+    pub const ALL: [InputFile; 2] = [InputFile::Sample, InputFile::Real];
+
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            InputFile::Sample => "sample.txt",
+            InputFile::Real => "input.txt",
+        }
+    }
+}
+
+impl fmt::Display for InputFile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputFile::Sample => write!(f, "sample"),
+            InputFile::Real => write!(f, "input"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_every_kind_of_answer() {
+        let manifest: AnswerManifest =
+            "[sample]\npart1 = 24000\npart2 = \"CMZ\"\n\n[input]\npart2 = '''\n#..\n.#.'''\n"
+                .parse()
+                .unwrap();
+
+        assert_eq!(manifest.sample.part1, Some(Answer::Integer(24000)));
+        assert_eq!(manifest.sample.part2, Some(Answer::Text("CMZ".into())));
+        assert_eq!(manifest.input.part1, None);
+        assert_eq!(
+            manifest.input.part2,
+            Some(Answer::Rendering("#..\n.#.".into()))
+        );
+    }
+
+    #[test]
+    fn round_trips_through_toml() {
+        let manifest = AnswerManifest {
+            sample: Expected {
+                part1: Some(Answer::Integer(-3)),
+                part2: Some(Answer::Rendering("##\n..".into())),
+            },
+            input: Expected {
+                part1: Some(Answer::Text("RJERPEFC".into())),
+                part2: None,
+            },
+        };
+
+        let serialized = toml::to_string(&manifest).unwrap();
+        assert_eq!(serialized.parse::<AnswerManifest>().unwrap(), manifest);
+    }
+}
@@ -0,0 +1,183 @@
+/*!
+ * Reads the capital letters that some puzzles draw with lit pixels instead of giving an answer
+ * directly.
+ *
+ * Every puzzle uses the same font: letters are 4 pixels wide and 6 tall, with a blank column after
+ * each of them. Not every letter of the alphabet has shown up in a puzzle, so only the ones that
+ * did are known.
+ */
+use std::fmt;
+
+use crate::Grid;
+
+pub const GLYPH_WIDTH: usize = 4;
+pub const GLYPH_HEIGHT: usize = 6;
+/// Columns a glyph takes, including the blank one after it
+const GLYPH_SPACING: usize = GLYPH_WIDTH + 1;
+
+const FONT: [(char, [&str; GLYPH_HEIGHT]); 18] = [
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Glyphs that don't look like any known letter, which can also mean the pixels aren't text at all.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct UnknownGlyphs {
+    /// What could be read, with `?` for each unknown glyph
+    pub text: String,
+    /// Each unknown glyph, with its position (from 0) and its pixels as `#` and `.`
+    pub glyphs: Vec<(usize, String)>,
+}
+
+/**
+ * The letters drawn in `pixels`, where lit pixels are `true`.
+ *
+ * The pixels have to be exactly one letter tall, and letters are read from the left edge every 5
+ * columns. Fails if any letter is unknown, but still reads all the others.
+ */
+pub fn read(pixels: &Grid<bool>) -> Result<String, UnknownGlyphs> {
+    let mut text = String::new();
+    let mut unknown = vec![];
+
+    if pixels.height() != GLYPH_HEIGHT {
+        unknown.push((0, pixels.map(|&lit| pixel(lit)).to_string()));
+    } else {
+        for index in 0..(pixels.width() + 1) / GLYPH_SPACING {
+            let glyph = glyph_at(pixels, index * GLYPH_SPACING);
+
+            match letter(&glyph) {
+                Some(letter) => text.push(letter),
+                None => {
+                    text.push('?');
+                    unknown.push((index, glyph.join("\n")));
+                }
+            }
+        }
+    }
+
+    if unknown.is_empty() {
+        Ok(text)
+    } else {
+        Err(UnknownGlyphs {
+            text,
+            glyphs: unknown,
+        })
+    }
+}
+
+// The rows of the glyph starting at column `left`, blank past the right edge
+fn glyph_at(pixels: &Grid<bool>, left: usize) -> Vec<String> {
+    (0..GLYPH_HEIGHT)
+        .map(|y| {
+            (left..left + GLYPH_WIDTH)
+                .map(|x| pixel(pixels.get((x, y)).copied().unwrap_or_default()))
+                .collect()
+        })
+        .collect()
+}
+
+fn letter(glyph: &[String]) -> Option<char> {
+    FONT.iter()
+        .find(|(_, rows)| rows.iter().eq(glyph.iter()))
+        .map(|&(letter, _)| letter)
+}
+
+fn pixel(lit: bool) -> char {
+    if lit {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+impl fmt::Display for UnknownGlyphs {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Unable to read {} of the letters in {}",
+            self.glyphs.len(),
+            self.text
+        )?;
+
+        for (index, glyph) in &self.glyphs {
+            write!(f, "\n\nGlyph {}:\n{}", index, glyph)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::error::Error for UnknownGlyphs {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+
+    fn pixels(text: &str) -> Grid<bool> {
+        Grid::parse(&Input::from_text(text), |char| Ok(char == '#')).unwrap()
+    }
+
+    #[test]
+    fn reads_letters() {
+        let pixels = pixels(
+            "\
+###....##.####.###..
+#..#....#.#....#..#.
+#..#....#.###..#..#.
+###.....#.#....###..
+#.#..#..#.#....#.#..
+#..#..##..####.#..#.",
+        );
+
+        assert_eq!(read(&pixels), Ok("RJER".into()));
+    }
+
+    #[test]
+    fn reports_unknown_glyphs() {
+        let pixels = pixels(
+            "\
+.##..#...
+#..#.#...
+#..#.#...
+####.#...
+#..#.#...
+#..#.#...",
+        );
+
+        let error = read(&pixels).unwrap_err();
+        assert_eq!(error.text, "A?");
+        assert_eq!(
+            error.glyphs,
+            vec![(1, "#...\n#...\n#...\n#...\n#...\n#...".into())]
+        );
+    }
+
+    #[test]
+    fn every_letter_is_different() {
+        for (i, (letter, rows)) in FONT.iter().enumerate() {
+            assert!(rows.iter().all(|row| row.len() == GLYPH_WIDTH));
+            assert!(
+                FONT[i + 1..].iter().all(|(_, other)| other != rows),
+                "{} looks like another letter",
+                letter
+            );
+        }
+    }
+}
@@ -0,0 +1,212 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::str::FromStr;
+
+/**
+ * Error for input that doesn't have the expected shape, pointing at the exact place where it went
+ * wrong.
+ *
+ * `FromStr` implementations only see the text they're given, so they create the error from that
+ * text and the part of it that is wrong. The loop going through the input knows where that text
+ * came from, and adds the line number and file name with `at_line` and `in_file` (which
+ * `Input::parse_lines` does on its own). Displayed, the error shows the offending line with the
+ * wrong part underlined:
+ *
+ * ```text
+ * Invalid direction X
+ *  --> day09/input.txt:3:1
+ *   |
+ * 3 | X 4
+ *   | ^
+ * ```
+ */
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    message: String,
+    text: String,
+    /// Characters of `text` the error is about, counting from 0
+    columns: Range<usize>,
+    /// 1-based, once known
+    line: Option<usize>,
+    file: Option<String>,
+}
+
+pub type ParseResult<T> = Result<T, ParseError>;
+
+impl ParseError {
+    /// Error about the whole of `text`.
+    pub fn new(message: impl Into<String>, text: &str) -> Self {
+        ParseError::with_columns(message, text, 0..text.chars().count())
+    }
+
+    /// Error about `part`, which is usually a slice of `text` (e.g. one of the words in a line).
+    pub fn at(message: impl Into<String>, text: &str, part: &str) -> Self {
+        let start = offset_in(text, part).or_else(|| text.find(part));
+
+        match start {
+            Some(start) => {
+                let column = text[..start].chars().count();
+                ParseError::with_columns(message, text, column..column + part.chars().count())
+            }
+            None => ParseError::new(message, text),
+        }
+    }
+
+    /// Error about the characters of `text` in `columns`, counting from 0.
+    pub fn with_columns(message: impl Into<String>, text: &str, columns: Range<usize>) -> Self {
+        ParseError {
+            message: message.into(),
+            text: text.into(),
+            columns,
+            line: None,
+            file: None,
+        }
+    }
+
+    /// Sets the 1-based number of the line the text was on.
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = Some(line);
+        self
+    }
+
+    /// Sets the name of the file the text came from, unless it was already set.
+    pub fn in_file(mut self, file: &str) -> Self {
+        self.file.get_or_insert_with(|| file.into());
+        self
+    }
+
+    /**
+     * Moves an error about `part` of `text` so it's about `text` instead, for parsers that hand part
+     * of a line to another parser. The columns are shifted to where that part is.
+     */
+    pub fn within(self, text: &str, part: &str) -> Self {
+        let shift = offset_in(text, part)
+            .or_else(|| text.find(part))
+            .map_or(0, |start| text[..start].chars().count());
+
+        ParseError {
+            text: text.into(),
+            columns: self.columns.start + shift..self.columns.end + shift,
+            ..self
+        }
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    pub fn line(&self) -> Option<usize> {
+        self.line
+    }
+
+    /// 1-based column where the wrong part starts.
+    pub fn column(&self) -> usize {
+        self.columns.start + 1
+    }
+
+    pub fn file(&self) -> Option<&str> {
+        self.file.as_deref()
+    }
+}
+
+/// Parses `part` of `text` as a number, failing with an error that points at it.
+pub fn parse_number<T: FromStr>(text: &str, part: &str) -> ParseResult<T> {
+    part.parse()
+        .map_err(|_| ParseError::at(format!("Invalid number {}", part), text, part))
+}
+
+// Start of `part` in `text`, in bytes, if `part` is a slice of `text`
+fn offset_in(text: &str, part: &str) -> Option<usize> {
+    let start = (part.as_ptr() as usize).checked_sub(text.as_ptr() as usize)?;
+    (start + part.len() <= text.len()).then_some(start)
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let location = match (&self.file, self.line) {
+            (Some(file), Some(line)) => format!("{}:{}:{}", file, line, self.column()),
+            (Some(file), None) => format!("{}, column {}", file, self.column()),
+            (None, Some(line)) => format!("line {}, column {}", line, self.column()),
+            (None, None) => format!("column {}", self.column()),
+        };
+
+        if f.alternate() {
+            return write!(f, "{}: {}", location, self.message);
+        }
+
+        let number = self.line.map(|line| line.to_string()).unwrap_or_default();
+        let gutter = " ".repeat(number.len());
+        let underline = " ".repeat(self.columns.start) + &"^".repeat(self.columns.len().max(1));
+
+        writeln!(f, "{}", self.message)?;
+        writeln!(f, "{} --> {}", gutter, location)?;
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", number, self.text)?;
+        write!(f, "{} | {}", gutter, underline)
+    }
+}
+
+impl Error for ParseError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn points_at_part_of_the_text() {
+        let line = "move 1 from x to 1";
+        let error = ParseError::at("Invalid stack x", line, &line[12..13])
+            .at_line(12)
+            .in_file("day05/input.txt");
+
+        assert_eq!(error.column(), 13);
+        assert_eq!(
+            error.to_string(),
+            "Invalid stack x\n   \
+                --> day05/input.txt:12:13\n   \
+                |\n\
+             12 | move 1 from x to 1\n   \
+                |             ^"
+        );
+    }
+
+    #[test]
+    fn fits_in_a_single_line_with_alternate_format() {
+        let error = ParseError::new("Invalid instruction", "addy 3")
+            .at_line(4)
+            .in_file("day10/sample.txt");
+        assert_eq!(
+            format!("{:#}", error),
+            "day10/sample.txt:4:1: Invalid instruction"
+        );
+    }
+
+    #[test]
+    fn finds_parts_that_are_not_slices() {
+        let error = ParseError::at("Invalid number", "addx 1o", "1o");
+        assert_eq!(error.column(), 6);
+        assert!(error.to_string().ends_with(" | addx 1o\n |      ^^"));
+    }
+
+    #[test]
+    fn moves_errors_into_the_enclosing_text() {
+        let line = "2-4,6-x";
+        let error = ParseError::at("Invalid number x", "6-x", "x").within(line, &line[4..]);
+        assert_eq!(error.column(), 7);
+        assert_eq!(error.text(), line);
+    }
+
+    #[test]
+    fn describes_location_without_line() {
+        let error = ParseError::new("Empty line", "");
+        assert_eq!(
+            error.to_string(),
+            "Empty line\n --> column 1\n |\n | \n | ^"
+        );
+    }
+}
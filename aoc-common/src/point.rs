@@ -0,0 +1,119 @@
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+/**
+ * A point (or the difference between two points) on a 2D plane with integer coordinates.
+ *
+ * Nothing in the type says which way `y` grows. Puzzles that draw their maps on screen usually
+ * have it grow downwards, like `Grid` rows and the deltas of `Direction` do.
+ */
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: isize,
+    pub y: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(x: isize, y: isize) -> Self {
+        Point { x, y }
+    }
+
+    /// Distance moving only horizontally and vertically.
+    pub fn manhattan(self, other: Point) -> usize {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// Distance moving in any of the 8 directions, diagonals included.
+    pub fn chebyshev(self, other: Point) -> usize {
+        self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+    }
+
+    /// Each coordinate turned into -1, 0 or 1, which is one step towards the point from the origin.
+    pub fn signum(self) -> Point {
+        Point::new(self.x.signum(), self.y.signum())
+    }
+}
+
+impl From<(isize, isize)> for Point {
+    fn from((x, y): (isize, isize)) -> Self {
+        Point::new(x, y)
+    }
+}
+
+impl Add for Point {
+    type Output = Point;
+
+    fn add(self, other: Point) -> Self::Output {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Point {
+    type Output = Point;
+
+    fn sub(self, other: Point) -> Self::Output {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl SubAssign for Point {
+    fn sub_assign(&mut self, other: Point) {
+        *self = *self - other;
+    }
+}
+
+impl Mul<isize> for Point {
+    type Output = Point;
+
+    fn mul(self, factor: isize) -> Self::Output {
+        Point::new(self.x * factor, self.y * factor)
+    }
+}
+
+impl Neg for Point {
+    type Output = Point;
+
+    fn neg(self) -> Self::Output {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn does_arithmetic() {
+        let mut point = Point::new(2, -3);
+        point += Point::new(1, 1);
+
+        assert_eq!(point, Point::new(3, -2));
+        assert_eq!(point - Point::new(3, 3), Point::new(0, -5));
+        assert_eq!(point * 2, Point::new(6, -4));
+        assert_eq!(-point, Point::from((-3, 2)));
+    }
+
+    #[test]
+    fn measures_distances() {
+        let a = Point::new(8, 7);
+        let b = Point::new(2, 10);
+
+        assert_eq!(a.manhattan(b), 9);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(b.manhattan(b), 0);
+    }
+
+    #[test]
+    fn steps_towards_points() {
+        assert_eq!(Point::new(-4, 0).signum(), Point::new(-1, 0));
+        assert_eq!(Point::new(2, 7).signum(), Point::new(1, 1));
+        assert_eq!(Point::ORIGIN.signum(), Point::ORIGIN);
+    }
+}
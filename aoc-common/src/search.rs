@@ -0,0 +1,266 @@
+/*!
+ * Shortest path searches over graphs that are only known through a function that gives the
+ * neighbours of a node, so they work the same for grids, state machines or anything in between.
+ *
+ * Every search takes the node to start from and a function that tells whether a node is the goal,
+ * and returns the cheapest `Path` to the first goal it reaches, or `None` if there's no way to
+ * reach one.
+ */
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+use std::hash::Hash;
+
+/// The nodes from the start to the goal (both included), along with the total cost of the moves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N> {
+    pub cost: usize,
+    pub nodes: Vec<N>,
+}
+
+/// Breadth-first search, for graphs where every move costs 1.
+pub fn bfs<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = Nodes::new(start);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(index) = queue.pop_front() {
+        let node = nodes.node(index).clone();
+
+        if is_goal(&node) {
+            return Some(nodes.path_to(index));
+        }
+
+        let cost = nodes.cost(index) + 1;
+
+        for neighbour in neighbours(&node) {
+            if let (neighbour, true) = nodes.reach(neighbour, index, cost) {
+                queue.push_back(neighbour);
+            }
+        }
+    }
+
+    None
+}
+
+/// Dijkstra's algorithm, for graphs where `neighbours` also gives the cost of moving to each one.
+pub fn dijkstra<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, neighbours, |_| 0, is_goal)
+}
+
+/**
+ * A* search, which is Dijkstra's algorithm looking first at the nodes that `heuristic` estimates
+ * to be closer to the goal. The path is only guaranteed to be the cheapest one if the heuristic
+ * never overestimates the remaining cost.
+ */
+pub fn astar<N, I>(
+    start: N,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), 0, 0))]);
+    let mut nodes = Nodes::new(start);
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        // A cheaper way to this node was found after this entry was queued
+        if cost > nodes.cost(index) {
+            continue;
+        }
+
+        let node = nodes.node(index).clone();
+
+        if is_goal(&node) {
+            return Some(nodes.path_to(index));
+        }
+
+        for (neighbour, step) in neighbours(&node) {
+            let estimate = heuristic(&neighbour);
+
+            if let (neighbour, true) = nodes.reach(neighbour, index, cost + step) {
+                queue.push(Reverse((cost + step + estimate, cost + step, neighbour)));
+            }
+        }
+    }
+
+    None
+}
+
+// Every node seen so far, with the cheapest known cost to reach it and where it was reached from.
+// Nodes are referred to by index, so the queues don't need to clone or compare them.
+struct Nodes<N> {
+    nodes: Vec<N>,
+    indices: HashMap<N, usize>,
+    costs: Vec<usize>,
+    parents: Vec<Option<usize>>,
+}
+
+impl<N: Clone + Eq + Hash> Nodes<N> {
+    fn new(start: N) -> Self {
+        Nodes {
+            indices: HashMap::from([(start.clone(), 0)]),
+            nodes: vec![start],
+            costs: vec![0],
+            parents: vec![None],
+        }
+    }
+
+    fn node(&self, index: usize) -> &N {
+        &self.nodes[index]
+    }
+
+    fn cost(&self, index: usize) -> usize {
+        self.costs[index]
+    }
+
+    // Records reaching `node` from `parent` with `cost`, returning its index and whether that's
+    // cheaper than any way of reaching it seen before
+    fn reach(&mut self, node: N, parent: usize, cost: usize) -> (usize, bool) {
+        match self.indices.get(&node) {
+            Some(&index) if self.costs[index] <= cost => (index, false),
+            Some(&index) => {
+                self.costs[index] = cost;
+                self.parents[index] = Some(parent);
+                (index, true)
+            }
+            None => {
+                let index = self.nodes.len();
+                self.indices.insert(node.clone(), index);
+                self.nodes.push(node);
+                self.costs.push(cost);
+                self.parents.push(Some(parent));
+                (index, true)
+            }
+        }
+    }
+
+    fn path_to(&self, index: usize) -> Path<N> {
+        let mut nodes = vec![];
+        let mut current = Some(index);
+
+        while let Some(index) = current {
+            nodes.push(self.nodes[index].clone());
+            current = self.parents[index];
+        }
+
+        nodes.reverse();
+
+        Path {
+            cost: self.costs[index],
+            nodes,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Grid, Input, Point};
+
+    // '#' are walls, and digits cost that much to step on
+    fn maze() -> Grid<char> {
+        let text = "S.#...\n.##.#.\n.5..#E\n1..#..\n";
+        Grid::parse(&Input::from_text(text), Ok).unwrap()
+    }
+
+    type Position = (usize, usize);
+
+    fn open_neighbours(maze: &Grid<char>, &position: &Position) -> Vec<(Position, usize)> {
+        maze.neighbours(position)
+            .filter(|&neighbour| maze[neighbour] != '#')
+            .map(|neighbour| {
+                (
+                    neighbour,
+                    maze[neighbour].to_digit(10).unwrap_or(1) as usize,
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn finds_fewest_steps_with_bfs() {
+        let maze = maze();
+        let path = bfs(
+            (0, 0),
+            |position| {
+                open_neighbours(&maze, position)
+                    .into_iter()
+                    .map(|(next, _)| next)
+            },
+            |&position| maze[position] == 'E',
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 11);
+        assert_eq!(path.nodes.len(), 12);
+        assert_eq!(path.nodes.first(), Some(&(0, 0)));
+        assert_eq!(path.nodes.last(), Some(&(5, 2)));
+    }
+
+    #[test]
+    fn finds_cheapest_path_with_dijkstra() {
+        let maze = maze();
+        let path = dijkstra(
+            (0, 0),
+            |position| open_neighbours(&maze, position),
+            |&position| maze[position] == 'E',
+        )
+        .unwrap();
+
+        // Going through the 5 is shorter, but going around it is cheaper
+        assert_eq!(path.cost, 13);
+        assert!(!path.nodes.contains(&(1, 2)));
+        assert!(path
+            .nodes
+            .windows(2)
+            .all(|step| maze.neighbours(step[0]).any(|next| next == step[1])));
+    }
+
+    #[test]
+    fn agrees_with_dijkstra_using_a_heuristic() {
+        let maze = maze();
+        let goal = Point::new(5, 2);
+        let distance =
+            |&(x, y): &(usize, usize)| Point::new(x as isize, y as isize).manhattan(goal);
+
+        let path = astar(
+            (0, 0),
+            |position| open_neighbours(&maze, position),
+            distance,
+            |&position| distance(&position) == 0,
+        )
+        .unwrap();
+
+        assert_eq!(path.cost, 13);
+    }
+
+    #[test]
+    fn gives_up_on_unreachable_goals() {
+        let maze = maze();
+        let path = dijkstra(
+            (0, 0),
+            |position| open_neighbours(&maze, position),
+            |&position| maze[position] == '#',
+        );
+
+        assert_eq!(path, None);
+    }
+}
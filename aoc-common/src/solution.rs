@@ -0,0 +1,138 @@
+use std::error::Error;
+use std::fmt;
+
+use crate::{Answer, FrameSink, Input};
+
+/**
+ * A day's puzzle, split into the three steps every day goes through: turning the input into some
+ * representation, and then answering each part of the challenge from it.
+ *
+ * The type implementing the trait is the parsed representation itself. `parse` is the only place
+ * that looks at the raw input, and each part gets a shared reference to the result, so parts that
+ * need to mutate state (e.g. run a simulation) work on their own copy.
+ */
+pub trait Solution {
+    fn parse(input: &Input) -> anyhow::Result<Self>
+    where
+        Self: Sized;
+
+    fn part1(&self) -> anyhow::Result<Answer>;
+
+    /// Days that only solved the first part so far can leave this out.
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Err(Unsolved(Part::Two).into())
+    }
+
+    fn solve(&self, part: Part) -> anyhow::Result<Answer> {
+        // Span names have to be known at compile time
+        let span = match part {
+            Part::One => tracing::info_span!("part1"),
+            Part::Two => tracing::info_span!("part2"),
+        };
+        let _entered = span.enter();
+
+        let answer = match part {
+            Part::One => self.part1(),
+            Part::Two => self.part2(),
+        };
+
+        match &answer {
+            Ok(answer) => tracing::debug!(%answer, "Solved"),
+            Err(error) => tracing::debug!(%error, "Failed"),
+        }
+
+        answer
+    }
+
+    /// Runs the simulation behind `part` again, showing each of its steps in `sink`. Only days
+    /// that simulate something implement it, or days that have a picture worth looking at.
+    fn animate(&self, part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let _ = sink;
+        anyhow::bail!("{} doesn't have an animation", part)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Part {
+    One,
+    Two,
+}
+
+impl Part {
+    pub const ALL: [Part; 2] = [Part::One, Part::Two];
+
+    pub fn number(&self) -> u8 {
+        match self {
+            Part::One => 1,
+            Part::Two => 2,
+        }
+    }
+}
+
+impl TryFrom<u8> for Part {
+    type Error = anyhow::Error;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            1 => Ok(Part::One),
+            2 => Ok(Part::Two),
+            n => anyhow::bail!("Invalid part {}, expected 1 or 2", n),
+        }
+    }
+}
+
+impl fmt::Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Part {}", self.number())
+    }
+}
+
+/**
+ * Error for parts that don't have a solution yet.
+ *
+ * Runners tell it apart from real failures: they print that the part isn't solved and go on with
+ * the other one, instead of stopping.
+ */
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Unsolved(pub Part);
+
+impl fmt::Display for Unsolved {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} is not solved yet", self.0)
+    }
+}
+
+impl Error for Unsolved {}
+
+/// Prints a part's answer, moving renderings to their own lines so they stay aligned.
+pub fn print_answer(part: Part, answer: &Answer) {
+    if answer.is_rendering() {
+        println!("{}:\n{}", part, answer);
+    } else {
+        println!("{}: {}", part, answer);
+    }
+}
+
+/// Prints that a part isn't solved, in place of its answer.
+pub fn print_unsolved(part: Part) {
+    println!("{}: not solved yet", part);
+}
+
+/// Entry point of every day's binary: parses the input given in the command line and prints the
+/// answer to both parts, logging as `RUST_LOG` says. Only failing parts make it fail, not unsolved
+/// ones.
+pub fn run<S: Solution>() -> anyhow::Result<()> {
+    crate::init_logging(None)?;
+    let input = Input::from_args()?;
+    let solution = tracing::info_span!("parse").in_scope(|| S::parse(&input))?;
+
+    for part in Part::ALL {
+        match solution.solve(part) {
+            Ok(answer) => print_answer(part, &answer),
+            Err(error) if error.is::<Unsolved>() => print_unsolved(part),
+            Err(error) => return Err(error),
+        }
+    }
+
+    Ok(())
+}
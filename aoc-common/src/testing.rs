@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use crate::{AnswerManifest, Input, InputFile, Part, Solution};
+
+/**
+ * Generates a test for each part of a day, checking the answer for its `sample.txt` against the
+ * one recorded in its `answers.toml`. Meant to be called once at the end of a day's `lib.rs`, as
+ * in `aoc_common::sample_tests!(Day01);`.
+ */
+#[macro_export]
+macro_rules! sample_tests {
+    ($solution:ty) => {
+        #[cfg(test)]
+        mod sample_tests {
+            use super::*;
+
+            #[test]
+            fn part1() {
+                $crate::testing::check_sample::<$solution>(
+                    env!("CARGO_MANIFEST_DIR"),
+                    $crate::Part::One,
+                );
+            }
+
+            #[test]
+            fn part2() {
+                $crate::testing::check_sample::<$solution>(
+                    env!("CARGO_MANIFEST_DIR"),
+                    $crate::Part::Two,
+                );
+            }
+        }
+    };
+}
+
+/// Solves `part` for the sample in `directory` and asserts that it matches the expected answer.
+/// Parts without an expected answer (e.g. not solved yet) are skipped.
+pub fn check_sample<S: Solution>(directory: &str, part: Part) {
+    let directory = Path::new(directory);
+    let manifest = AnswerManifest::from_directory(directory).expect("Unable to read answers");
+
+    let Some(expected) = manifest.expected(InputFile::Sample).get(part) else {
+        eprintln!("No sample answer for {}, skipping", part);
+        return;
+    };
+
+    let input = Input::from_path(directory.join(InputFile::Sample.file_name()))
+        .expect("Unable to read sample");
+    let solution = S::parse(&input).expect("Unable to parse sample");
+    let actual = solution.solve(part).expect("Unable to solve sample");
+
+    assert_eq!(&actual, expected, "Wrong answer for {}", part);
+}
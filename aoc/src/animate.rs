@@ -0,0 +1,334 @@
+use std::fs;
+use std::io::{self, Write};
+use std::ops::{ControlFlow, RangeInclusive};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use aoc_common::{Frame, FrameSink, Part, Solution};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+
+pub struct AnimateOptions {
+    /// Frames per second the terminal player starts at
+    pub fps: f64,
+    /// Directory to write the frames to instead of playing them
+    pub headless: Option<PathBuf>,
+}
+
+/// Speeds the player can go at, which `--fps` is checked against.
+pub const FPS_RANGE: RangeInclusive<f64> = 1.0..=120.0;
+
+const KEYS: &str = "space: pause, n: step, +/-: speed, q: quit";
+
+/// Plays the animation of `part` in the terminal, or writes its frames to files when headless.
+pub fn animate(
+    solution: &dyn Solution,
+    part: Part,
+    options: &AnimateOptions,
+) -> anyhow::Result<()> {
+    match &options.headless {
+        Some(directory) => {
+            let mut dump = FrameDump::new(directory, part)?;
+            solution.animate(part, &mut dump)?;
+            dump.finish()?;
+            eprintln!(
+                "Wrote {} frames of {} to {}",
+                dump.count,
+                part,
+                directory.display()
+            );
+        }
+        None => {
+            let mut player = Player::start(options.fps)?;
+            solution.animate(part, &mut player)?;
+            player.finish()?;
+        }
+    }
+
+    Ok(())
+}
+
+/**
+ * Plays frames in the terminal, drawing each one over the previous with ANSI escape codes.
+ *
+ * The terminal is put in raw mode so single key presses can pause the animation, step through it
+ * one frame at a time or change its speed. Everything is restored when the player is dropped.
+ */
+pub struct Player {
+    fps: f64,
+    paused: bool,
+    stopped: bool,
+    last_frame: Option<Frame>,
+    out: io::Stdout,
+}
+
+impl Player {
+    pub fn start(fps: f64) -> anyhow::Result<Self> {
+        anyhow::ensure!(
+            FPS_RANGE.contains(&fps),
+            "The speed has to be between {} and {} frames per second",
+            FPS_RANGE.start(),
+            FPS_RANGE.end()
+        );
+        terminal::enable_raw_mode()
+            .context("Unable to control the terminal, use --headless to write frames to files")?;
+
+        let mut out = io::stdout();
+        // Switch to the alternate screen and hide the cursor
+        write!(out, "\x1b[?1049h\x1b[?25l")?;
+
+        Ok(Player {
+            fps,
+            paused: false,
+            stopped: false,
+            last_frame: None,
+            out,
+        })
+    }
+
+    /// Keeps the last frame on screen until a key is pressed, unless the player was quit already.
+    pub fn finish(&mut self) -> anyhow::Result<()> {
+        if self.stopped {
+            return Ok(());
+        }
+
+        self.paused = true;
+        self.draw("Done, press any key to exit")?;
+
+        loop {
+            if let Event::Key(key) = event::read()? {
+                if key.kind == KeyEventKind::Press {
+                    return Ok(());
+                }
+            }
+        }
+    }
+
+    fn set_fps(&mut self, fps: f64) {
+        self.fps = fps.clamp(*FPS_RANGE.start(), *FPS_RANGE.end());
+    }
+
+    fn frame_duration(&self) -> Duration {
+        Duration::from_secs_f64(1.0 / self.fps)
+    }
+
+    fn status(&self) -> String {
+        let state = if self.paused { "paused" } else { "playing" };
+        format!("{} at {} fps ({})", state, self.fps, KEYS)
+    }
+
+    fn draw(&mut self, status: &str) -> io::Result<()> {
+        let Some(frame) = &self.last_frame else {
+            return Ok(());
+        };
+
+        // Raw mode doesn't move back to the start of the line on \n, and lines from the previous
+        // frame are cleared as they're overwritten rather than all at once, to avoid flickering
+        write!(self.out, "\x1b[H")?;
+        for line in frame.to_string().lines() {
+            write!(self.out, "{}\x1b[K\r\n", line)?;
+        }
+        write!(self.out, "\x1b[K\r\n{}\x1b[K\x1b[J", status)?;
+
+        self.out.flush()
+    }
+
+    // Waits until it's time for the next frame, handling the keys pressed meanwhile
+    fn wait(&mut self) -> io::Result<ControlFlow<()>> {
+        let deadline = Instant::now() + self.frame_duration();
+
+        loop {
+            let now = Instant::now();
+            if !self.paused && now >= deadline {
+                return Ok(ControlFlow::Continue(()));
+            }
+
+            let timeout = if self.paused {
+                Duration::from_secs(60)
+            } else {
+                deadline - now
+            };
+
+            if !event::poll(timeout)? {
+                continue;
+            }
+
+            let Event::Key(key) = event::read()? else {
+                continue;
+            };
+
+            if key.kind != KeyEventKind::Press {
+                continue;
+            }
+
+            match Action::from(key) {
+                Action::TogglePause => self.paused = !self.paused,
+                Action::Step if self.paused => return Ok(ControlFlow::Continue(())),
+                Action::Faster => self.set_fps(self.fps * 2.0),
+                Action::Slower => self.set_fps(self.fps / 2.0),
+                Action::Quit => return Ok(ControlFlow::Break(())),
+                Action::Step | Action::None => continue,
+            }
+
+            self.draw(&self.status())?;
+        }
+    }
+}
+
+impl FrameSink for Player {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()> {
+        self.last_frame = Some(frame);
+
+        let flow = self.draw(&self.status()).and_then(|_| self.wait());
+        // Errors writing to the terminal or reading keys stop the animation too
+        let flow = flow.unwrap_or(ControlFlow::Break(()));
+
+        self.stopped = flow.is_break();
+        flow
+    }
+}
+
+impl Drop for Player {
+    fn drop(&mut self) {
+        let _ = write!(self.out, "\x1b[?25h\x1b[?1049l");
+        let _ = self.out.flush();
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Action {
+    TogglePause,
+    Step,
+    Faster,
+    Slower,
+    Quit,
+    None,
+}
+
+impl From<KeyEvent> for Action {
+    fn from(key: KeyEvent) -> Self {
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Action::Quit,
+            KeyCode::Char(' ') => Action::TogglePause,
+            KeyCode::Char('n') | KeyCode::Right => Action::Step,
+            KeyCode::Char('+') | KeyCode::Char('=') | KeyCode::Up => Action::Faster,
+            KeyCode::Char('-') | KeyCode::Down => Action::Slower,
+            KeyCode::Char('q') | KeyCode::Esc => Action::Quit,
+            _ => Action::None,
+        }
+    }
+}
+
+/**
+ * Writes each frame to its own numbered text file (`part1-00001.txt`, `part1-00002.txt`...), for
+ * looking at them without a terminal or comparing them in tests.
+ */
+pub struct FrameDump {
+    directory: PathBuf,
+    part: Part,
+    count: usize,
+    error: Option<anyhow::Error>,
+}
+
+impl FrameDump {
+    pub fn new(directory: &Path, part: Part) -> anyhow::Result<Self> {
+        fs::create_dir_all(directory)
+            .with_context(|| format!("Unable to create {}", directory.display()))?;
+
+        Ok(FrameDump {
+            directory: directory.into(),
+            part,
+            count: 0,
+            error: None,
+        })
+    }
+
+    /// Fails if writing any of the frames failed.
+    pub fn finish(&mut self) -> anyhow::Result<()> {
+        self.error.take().map_or(Ok(()), Err)
+    }
+
+    fn path(&self, number: usize) -> PathBuf {
+        self.directory
+            .join(format!("part{}-{:05}.txt", self.part.number(), number))
+    }
+}
+
+impl FrameSink for FrameDump {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()> {
+        let path = self.path(self.count + 1);
+
+        match fs::write(&path, frame.to_string() + "\n") {
+            Ok(()) => {
+                self.count += 1;
+                ControlFlow::Continue(())
+            }
+            Err(error) => {
+                let error = anyhow::Error::new(error)
+                    .context(format!("Unable to write {}", path.display()));
+                self.error = Some(error);
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Grid, Input};
+
+    #[test]
+    fn writes_numbered_frames() {
+        let directory = tempfile::tempdir().unwrap();
+        let mut dump = FrameDump::new(directory.path(), Part::Two).unwrap();
+
+        for step in 1..=2 {
+            let frame = Frame::new(format!("Step {}", step), Grid::new(2, 1, '#'));
+            assert_eq!(dump.show(frame), ControlFlow::Continue(()));
+        }
+        dump.finish().unwrap();
+
+        let second = fs::read_to_string(directory.path().join("part2-00002.txt")).unwrap();
+        assert_eq!(dump.count, 2);
+        assert_eq!(second, "Step 2\n##\n");
+    }
+
+    #[test]
+    fn writes_every_frame_of_day_10() {
+        let day = crate::days::find(10).unwrap();
+        let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("..")
+            .join(day.directory())
+            .join("sample.txt");
+        let input = Input::from_path(path).unwrap();
+        let solution = day.parse(&input).unwrap();
+        let directory = tempfile::tempdir().unwrap();
+        let options = AnimateOptions {
+            fps: 10.0,
+            headless: Some(directory.path().into()),
+        };
+
+        animate(solution.as_ref(), Part::Two, &options).unwrap();
+
+        let frames = fs::read_dir(directory.path()).unwrap().count();
+        let last = fs::read_to_string(directory.path().join("part2-00240.txt")).unwrap();
+        assert_eq!(frames, 240);
+        assert!(!last.lines().skip(1).any(|line| line.contains(' ')));
+    }
+
+    #[test]
+    fn maps_keys_to_actions() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+
+        assert_eq!(Action::from(key(KeyCode::Char(' '))), Action::TogglePause);
+        assert_eq!(Action::from(key(KeyCode::Right)), Action::Step);
+        assert_eq!(Action::from(key(KeyCode::Char('x'))), Action::None);
+        assert_eq!(
+            Action::from(KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)),
+            Action::Quit
+        );
+    }
+}
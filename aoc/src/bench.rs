@@ -0,0 +1,246 @@
+use std::fmt;
+use std::fs;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use anyhow::Context;
+use aoc_common::{Input, InputFile, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+use crate::table::print_table;
+
+pub struct BenchOptions {
+    pub iterations: usize,
+    /// Where to save the results, to be used as a baseline by later runs
+    pub save: Option<PathBuf>,
+    /// Results of a previous run to compare against
+    pub baseline: Option<PathBuf>,
+    /// How much slower (in percent) the median can get before it counts as a regression
+    pub threshold: f64,
+}
+
+/**
+ * Times parsing and solving each part of the given days on their real input, separately, running
+ * each of them `iterations` times.
+ *
+ * Each iteration parses the input from scratch and solves both parts from that, so timings of the
+ * parts never include parsing. Parts that fail (e.g. because they aren't solved yet) are left out.
+ */
+pub fn bench(days: &[&Day], options: &BenchOptions) -> anyhow::Result<()> {
+    anyhow::ensure!(options.iterations > 0, "At least one iteration is needed");
+
+    let baseline = options
+        .baseline
+        .as_ref()
+        .map(|path| Baseline::from_path(path))
+        .transpose()?;
+
+    let mut results = vec![];
+
+    for day in days {
+        let path = Path::new(&day.directory()).join(InputFile::Real.file_name());
+        let input = Input::from_path(path)?;
+        results.extend(time_day(day, &input, options.iterations)?);
+    }
+
+    let mut regressions = 0;
+    let rows: Vec<[String; 7]> = results
+        .iter()
+        .map(|result| {
+            let previous = baseline.as_ref().and_then(|baseline| baseline.find(result));
+            let change = previous.map(|previous| result.change_from(previous));
+            let is_regression = change.is_some_and(|change| change > options.threshold);
+
+            if is_regression {
+                regressions += 1;
+            }
+
+            [
+                result.day.to_string(),
+                result.step.to_string(),
+                format_duration(result.min()),
+                format_duration(result.median()),
+                format_duration(result.max()),
+                previous.map_or_else(|| "-".into(), |previous| format_duration(previous.median())),
+                match change {
+                    Some(change) if is_regression => format!("{:+.1}% REGRESSION", change),
+                    Some(change) => format!("{:+.1}%", change),
+                    None => "-".into(),
+                },
+            ]
+        })
+        .collect();
+
+    print_table(
+        ["Day", "Step", "Min", "Median", "Max", "Baseline", "Change"],
+        &rows,
+    );
+
+    if let Some(path) = &options.save {
+        let baseline = Baseline {
+            iterations: options.iterations,
+            results,
+        };
+        baseline.save(path)?;
+        println!("Saved results to {}", path.display());
+    }
+
+    anyhow::ensure!(
+        regressions == 0,
+        "Found {} regressions over {}%",
+        regressions,
+        options.threshold
+    );
+
+    Ok(())
+}
+
+fn time_day(day: &Day, input: &Input, iterations: usize) -> anyhow::Result<Vec<Timing>> {
+    let mut parse = Timing::new(day.number, Step::Parse);
+    let mut parts = Part::ALL.map(|part| Some(Timing::new(day.number, part.into())));
+
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let solution = black_box(day.parse(input)?);
+        parse.record(start.elapsed());
+
+        for (part, timing) in Part::ALL.into_iter().zip(parts.iter_mut()) {
+            let Some(recorded) = timing else {
+                continue;
+            };
+
+            let start = Instant::now();
+            let answer = black_box(solution.solve(part));
+            let elapsed = start.elapsed();
+
+            match answer {
+                Ok(_) => recorded.record(elapsed),
+                Err(_) => *timing = None,
+            }
+        }
+    }
+
+    Ok([Some(parse)].into_iter().chain(parts).flatten().collect())
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Step {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl From<Part> for Step {
+    fn from(part: Part) -> Self {
+        match part {
+            Part::One => Step::Part1,
+            Part::Two => Step::Part2,
+        }
+    }
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::Parse => write!(f, "parse"),
+            Step::Part1 => write!(f, "part 1"),
+            Step::Part2 => write!(f, "part 2"),
+        }
+    }
+}
+
+/// Every measurement of a single step of a day, in nanoseconds and sorted from fastest to slowest.
+#[derive(Debug, Serialize, Deserialize)]
+struct Timing {
+    day: u8,
+    step: Step,
+    samples_ns: Vec<u64>,
+}
+
+impl Timing {
+    fn new(day: u8, step: Step) -> Self {
+        Timing {
+            day,
+            step,
+            samples_ns: vec![],
+        }
+    }
+
+    fn record(&mut self, elapsed: Duration) {
+        let nanos = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        let position = self.samples_ns.partition_point(|&sample| sample < nanos);
+        self.samples_ns.insert(position, nanos);
+    }
+
+    fn min(&self) -> Duration {
+        Duration::from_nanos(self.samples_ns.first().copied().unwrap_or_default())
+    }
+
+    fn median(&self) -> Duration {
+        Duration::from_nanos(
+            self.samples_ns
+                .get(self.samples_ns.len() / 2)
+                .copied()
+                .unwrap_or_default(),
+        )
+    }
+
+    fn max(&self) -> Duration {
+        Duration::from_nanos(self.samples_ns.last().copied().unwrap_or_default())
+    }
+
+    /// Change of the median relative to `previous`, in percent (positive means slower).
+    fn change_from(&self, previous: &Timing) -> f64 {
+        let previous = previous.median().as_secs_f64();
+        let current = self.median().as_secs_f64();
+
+        if previous == 0.0 {
+            0.0
+        } else {
+            (current - previous) / previous * 100.0
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct Baseline {
+    iterations: usize,
+    results: Vec<Timing>,
+}
+
+impl Baseline {
+    fn from_path(path: &Path) -> anyhow::Result<Self> {
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read baseline {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("Invalid baseline in {}", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("Unable to write {}", path.display()))
+    }
+
+    fn find(&self, timing: &Timing) -> Option<&Timing> {
+        self.results
+            .iter()
+            .find(|previous| previous.day == timing.day && previous.step == timing.step)
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+
+    if nanos < 1_000 {
+        format!("{}ns", nanos)
+    } else if nanos < 1_000_000 {
+        format!("{:.2}µs", nanos as f64 / 1e3)
+    } else if nanos < 1_000_000_000 {
+        format!("{:.2}ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2}s", nanos as f64 / 1e9)
+    }
+}
@@ -0,0 +1,332 @@
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context};
+
+pub const BASE_URL: &str = "https://adventofcode.com";
+pub const YEAR: u16 = 2022;
+
+const USER_AGENT: &str = "github.com/julioolvr/aoc2022";
+const SESSION_VAR: &str = "AOC_SESSION";
+
+/**
+ * HTTP client for the Advent of Code website, authenticated with the session cookie of a logged in
+ * user.
+ *
+ * The website asks to keep automated requests to a minimum, so the client waits until at least
+ * `min_delay` has passed since its previous request before making a new one. With a `stamp_file`,
+ * the time of the last request is also kept on disk, so that requests stay spaced out across
+ * separate runs of the runner.
+ */
+pub struct Client {
+    base_url: String,
+    session: String,
+    min_delay: Duration,
+    last_request: Option<Instant>,
+    stamp_file: Option<PathBuf>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: impl Into<String>, session: impl Into<String>) -> Self {
+        Client {
+            base_url: base_url.into(),
+            session: session.into(),
+            min_delay: Duration::from_secs(3),
+            last_request: None,
+            stamp_file: None,
+            agent: ureq::AgentBuilder::new().user_agent(USER_AGENT).build(),
+        }
+    }
+
+    /// Client for the real website, with the session from `load_session`, which remembers its last
+    /// request next to the session file.
+    pub fn from_env() -> anyhow::Result<Self> {
+        let client = Client::new(BASE_URL, load_session()?);
+
+        Ok(match config_dir() {
+            Some(directory) => client.with_stamp_file(directory.join("last_request")),
+            None => client,
+        })
+    }
+
+    /// Keeps the time of the last request in `path`, shared by every client using the same file.
+    pub fn with_stamp_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.stamp_file = Some(path.into());
+        self
+    }
+
+    /// Only meant for tests, which don't need to be polite to the mock server.
+    #[cfg(test)]
+    pub fn with_min_delay(mut self, min_delay: Duration) -> Self {
+        self.min_delay = min_delay;
+        self
+    }
+
+    /// `path` is relative to the year, e.g. `/day/1/input`.
+    pub fn get(&mut self, path: &str) -> anyhow::Result<String> {
+        let url = self.url(path);
+        self.throttle();
+
+        let response = self
+            .agent
+            .get(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .call();
+
+        read_response(&url, response)
+    }
+
+    /// Posts `form` URL-encoded, the way the website's own forms do.
+    pub fn post_form(&mut self, path: &str, form: &[(&str, &str)]) -> anyhow::Result<String> {
+        let url = self.url(path);
+        self.throttle();
+
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .send_form(form);
+
+        read_response(&url, response)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}/{}{}", self.base_url.trim_end_matches('/'), YEAR, path)
+    }
+
+    fn throttle(&mut self) {
+        let elapsed = [
+            self.last_request.map(|last_request| last_request.elapsed()),
+            self.elapsed_since_stamp(),
+        ]
+        .into_iter()
+        .flatten()
+        .min();
+
+        if let Some(elapsed) = elapsed {
+            if elapsed < self.min_delay {
+                thread::sleep(self.min_delay - elapsed);
+            }
+        }
+
+        self.last_request = Some(Instant::now());
+        self.write_stamp();
+    }
+
+    // A missing or unreadable stamp is the same as no previous request
+    fn elapsed_since_stamp(&self) -> Option<Duration> {
+        let text = fs::read_to_string(self.stamp_file.as_ref()?).ok()?;
+        let last_request = UNIX_EPOCH + Duration::from_millis(text.trim().parse().ok()?);
+
+        // A stamp from the future means the clock went back, so wait the whole delay to be safe
+        Some(
+            SystemTime::now()
+                .duration_since(last_request)
+                .unwrap_or_default(),
+        )
+    }
+
+    // Failing to write the stamp only means the next run may not wait, so it doesn't stop requests
+    fn write_stamp(&self) {
+        let Some(path) = &self.stamp_file else {
+            return;
+        };
+        let Ok(now) = SystemTime::now().duration_since(UNIX_EPOCH) else {
+            return;
+        };
+
+        if let Some(directory) = path.parent() {
+            let _ = fs::create_dir_all(directory);
+        }
+        let _ = fs::write(path, now.as_millis().to_string());
+    }
+}
+
+fn read_response(
+    url: &str,
+    response: Result<ureq::Response, ureq::Error>,
+) -> anyhow::Result<String> {
+    match response {
+        Ok(response) => response
+            .into_string()
+            .with_context(|| format!("Unable to read response from {}", url)),
+        Err(ureq::Error::Status(status, response)) => {
+            let body = response.into_string().unwrap_or_default();
+            bail!("Request to {} failed with {}: {}", url, status, body.trim())
+        }
+        Err(error) => Err(error).with_context(|| format!("Request to {} failed", url)),
+    }
+}
+
+/// Reads the session token from the `AOC_SESSION` environment variable, or from the config file
+/// (`~/.config/aoc/session`) if it's not set.
+pub fn load_session() -> anyhow::Result<String> {
+    if let Ok(session) = env::var(SESSION_VAR) {
+        return Ok(session.trim().into());
+    }
+
+    let path = config_dir()
+        .context("Unable to find the config directory")?
+        .join("session");
+    let session = fs::read_to_string(&path).with_context(|| {
+        format!(
+            "Missing session token, set {} or write it to {}",
+            SESSION_VAR,
+            path.display()
+        )
+    })?;
+
+    Ok(session.trim().into())
+}
+
+/// Directory for files that are specific to the user rather than to the repository.
+pub fn config_dir() -> Option<PathBuf> {
+    let base = env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| PathBuf::from(home).join(".config")))?;
+
+    Some(base.join("aoc"))
+}
+
+/**
+ * Stand-in for the website in tests: an HTTP server on localhost that answers each request with the
+ * next of a list of canned responses, and keeps the requests it got so they can be inspected.
+ */
+#[cfg(test)]
+pub mod mock_server {
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    #[derive(Debug, Clone)]
+    pub struct Request {
+        pub method: String,
+        pub path: String,
+        pub headers: Vec<(String, String)>,
+        pub body: String,
+    }
+
+    impl Request {
+        pub fn header(&self, name: &str) -> Option<&str> {
+            self.headers
+                .iter()
+                .find(|(header, _)| header.eq_ignore_ascii_case(name))
+                .map(|(_, value)| value.as_str())
+        }
+    }
+
+    pub struct MockServer {
+        url: String,
+        requests: Arc<Mutex<Vec<Request>>>,
+    }
+
+    impl MockServer {
+        pub fn start(responses: Vec<(u16, &str)>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(vec![]));
+            let responses: Vec<(u16, String)> = responses
+                .into_iter()
+                .map(|(status, body)| (status, body.to_string()))
+                .collect();
+
+            let recorded = Arc::clone(&requests);
+            thread::spawn(move || {
+                for ((status, body), stream) in responses.into_iter().zip(listener.incoming()) {
+                    let mut stream = stream.unwrap();
+                    let request = read_request(&mut BufReader::new(&stream));
+                    recorded.lock().unwrap().push(request);
+
+                    write!(
+                        stream,
+                        "HTTP/1.1 {} Mock\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                        status,
+                        body.len(),
+                        body
+                    )
+                    .unwrap();
+                }
+            });
+
+            MockServer { url, requests }
+        }
+
+        pub fn url(&self) -> &str {
+            &self.url
+        }
+
+        pub fn requests(&self) -> Vec<Request> {
+            self.requests.lock().unwrap().clone()
+        }
+    }
+
+    fn read_request(reader: &mut impl BufRead) -> Request {
+        let mut request_line = String::new();
+        reader.read_line(&mut request_line).unwrap();
+        let mut request_line = request_line.split_whitespace();
+        let method = request_line.next().unwrap_or_default().to_string();
+        let path = request_line.next().unwrap_or_default().to_string();
+
+        let mut headers = vec![];
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            let line = line.trim_end();
+
+            if line.is_empty() {
+                break;
+            }
+
+            if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_string(), value.trim().to_string()));
+            }
+        }
+
+        let length = headers
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case("Content-Length"))
+            .map_or(0, |(_, value)| value.parse().unwrap());
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).unwrap();
+
+        Request {
+            method,
+            path,
+            headers,
+            body: String::from_utf8(body).unwrap(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::mock_server::MockServer;
+    use super::*;
+
+    #[test]
+    fn spaces_requests_of_clients_sharing_a_stamp_file() {
+        let server = MockServer::start(vec![(200, "first"), (200, "second")]);
+        let directory = tempfile::tempdir().unwrap();
+        let stamp_file = directory.path().join("last_request");
+        let min_delay = Duration::from_millis(300);
+
+        let client = || {
+            Client::new(server.url(), "token")
+                .with_min_delay(min_delay)
+                .with_stamp_file(&stamp_file)
+        };
+
+        assert_eq!(client().get("/day/1/input").unwrap(), "first");
+        let start = Instant::now();
+        assert_eq!(client().get("/day/2/input").unwrap(), "second");
+
+        // The stamp only keeps milliseconds, so the wait can be that much shorter
+        let tolerance = Duration::from_millis(5);
+        assert!(start.elapsed() >= min_delay - tolerance);
+    }
+}
@@ -0,0 +1,59 @@
+use aoc_common::{Input, Solution};
+
+/**
+ * Every day the runner knows about. Days are added by appending a line to `DAYS`, which is the
+ * only place that needs to know about the concrete type of each solution.
+ */
+pub const DAYS: &[Day] = &[
+    Day::new::<day01::Day01>(1),
+    Day::new::<day02::Day02>(2),
+    Day::new::<day03::Day03>(3),
+    Day::new::<day04::Day04>(4),
+    Day::new::<day05::Day05>(5),
+    Day::new::<day06::Day06>(6),
+    Day::new::<day07::Day07>(7),
+    Day::new::<day08::Day08>(8),
+    Day::new::<day09::Day09>(9),
+    Day::new::<day10::Day10>(10),
+    Day::new::<day11::Day11>(11),
+    Day::new::<day12::Day12>(12),
+    Day::new::<day13::Day13>(13),
+    Day::new::<day14::Day14>(14),
+    Day::new::<day15::Day15>(15),
+    Day::new::<day17::Day17>(17),
+];
+
+pub fn find(number: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|day| day.number == number)
+}
+
+/// Directory of a day's crate, relative to the root of the workspace.
+pub fn directory(number: u8) -> String {
+    format!("day{:02}", number)
+}
+
+pub struct Day {
+    pub number: u8,
+    parse: fn(&Input) -> anyhow::Result<Box<dyn Solution>>,
+}
+
+impl Day {
+    const fn new<S: Solution + 'static>(number: u8) -> Self {
+        Day {
+            number,
+            parse: parse_boxed::<S>,
+        }
+    }
+
+    pub fn parse(&self, input: &Input) -> anyhow::Result<Box<dyn Solution>> {
+        tracing::info_span!("parse", input = input.name()).in_scope(|| (self.parse)(input))
+    }
+
+    pub fn directory(&self) -> String {
+        directory(self.number)
+    }
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &Input) -> anyhow::Result<Box<dyn Solution>> {
+    Ok(Box::new(S::parse(input)?))
+}
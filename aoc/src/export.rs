@@ -0,0 +1,335 @@
+use std::collections::BTreeSet;
+use std::fs::File;
+use std::io::BufWriter;
+use std::ops::ControlFlow;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Context};
+use aoc_common::{Frame, FrameSink, Grid, Part, Solution};
+
+/// Width and height in pixels of each cell of a frame.
+const CELL_SIZE: usize = 4;
+const BACKGROUND: [u8; 3] = [24, 24, 32];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ImageFormat {
+    /// The last frame, as a still image
+    Png,
+    /// Every frame, as an animation
+    Gif,
+}
+
+impl ImageFormat {
+    fn from_path(path: &Path) -> anyhow::Result<Self> {
+        let extension = path.extension().and_then(|extension| extension.to_str());
+
+        match extension
+            .map(|extension| extension.to_ascii_lowercase())
+            .as_deref()
+        {
+            Some("png") => Ok(ImageFormat::Png),
+            Some("gif") => Ok(ImageFormat::Gif),
+            _ => bail!(
+                "Unable to export to {}, the file has to end in .png or .gif",
+                path.display()
+            ),
+        }
+    }
+}
+
+/**
+ * Saves the frames of the animation of `part` as an image: an animated GIF with every frame
+ * playing at `fps`, or a PNG with only the last one, depending on the extension of `path`.
+ *
+ * Each character in a frame becomes a square of pixels with the color `color` gives it. Frames of
+ * a GIF can have different sizes, so the simulation runs twice: once to find the size of the
+ * biggest frame, and once more to encode them, which saves keeping them all in memory.
+ */
+pub fn export(solution: &dyn Solution, part: Part, path: &Path, fps: f64) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        fps > 0.0,
+        "The speed has to be more than 0 frames per second"
+    );
+
+    match ImageFormat::from_path(path)? {
+        ImageFormat::Png => {
+            let mut last = LastFrame(None);
+            solution.animate(part, &mut last)?;
+            let frame = last.0.context("The animation doesn't have any frames")?;
+            write_png(path, &frame.cells)
+        }
+        ImageFormat::Gif => {
+            let mut measure = Measure::default();
+            solution.animate(part, &mut measure)?;
+            anyhow::ensure!(measure.frames > 0, "The animation doesn't have any frames");
+
+            let mut gif = GifSink::create(path, &measure, fps)?;
+            solution.animate(part, &mut gif)?;
+            gif.error.map_or(Ok(()), Err)
+        }
+    }
+}
+
+/// `path` with the number of `part` added to its name, for exporting several parts at once.
+pub fn path_for_part(path: &Path, part: Part) -> PathBuf {
+    let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+    let extension = path.extension().unwrap_or_default().to_string_lossy();
+
+    path.with_file_name(format!("{}-part{}.{}", stem, part.number(), extension))
+}
+
+/**
+ * Color of the pixels for a cell, so that the same symbols look alike across days: digits go from
+ * dark blue to yellow as they grow (heatmaps), lowercase letters from green to white (elevation),
+ * and rock, sand and the things that move or mark a path stand out from the dark background.
+ */
+fn color(cell: char) -> [u8; 3] {
+    match cell {
+        '.' | ' ' => BACKGROUND,
+        '#' => [200, 200, 200],
+        'o' => [230, 190, 90],
+        '@' => [240, 120, 40],
+        '+' | '|' | '-' => [110, 110, 120],
+        '0'..='9' => gradient([30, 40, 120], [250, 230, 60], cell as u8 - b'0', 9),
+        'a'..='z' => gradient([40, 110, 50], [240, 240, 230], cell as u8 - b'a', 25),
+        '↑' | '↓' | '←' | '→' | 'H' | 'T' | 'S' | 'E' => [220, 50, 50],
+        _ => [255, 0, 255],
+    }
+}
+
+fn gradient(from: [u8; 3], to: [u8; 3], step: u8, steps: u8) -> [u8; 3] {
+    let ratio = f64::from(step) / f64::from(steps);
+    let mix =
+        |from: u8, to: u8| (f64::from(from) + (f64::from(to) - f64::from(from)) * ratio) as u8;
+
+    [
+        mix(from[0], to[0]),
+        mix(from[1], to[1]),
+        mix(from[2], to[2]),
+    ]
+}
+
+// One value per pixel, row by row, for an image of `width` by `height` cells with `cells` in the
+// top left corner and the rest filled with `background`
+fn rasterize<P: Copy>(
+    cells: &Grid<char>,
+    (width, height): (usize, usize),
+    pixel: impl Fn(char) -> P,
+    background: P,
+) -> Vec<P> {
+    let mut pixels = Vec::with_capacity(width * height * CELL_SIZE * CELL_SIZE);
+
+    for y in 0..height * CELL_SIZE {
+        for x in 0..width * CELL_SIZE {
+            let cell = cells.get((x / CELL_SIZE, y / CELL_SIZE));
+            pixels.push(cell.map_or(background, |&cell| pixel(cell)));
+        }
+    }
+
+    pixels
+}
+
+fn write_png(path: &Path, cells: &Grid<char>) -> anyhow::Result<()> {
+    let size = (cells.width(), cells.height());
+    let pixels = rasterize(cells, size, color, BACKGROUND).concat();
+
+    let file = create(path)?;
+    let mut encoder = png::Encoder::new(
+        BufWriter::new(file),
+        (size.0 * CELL_SIZE) as u32,
+        (size.1 * CELL_SIZE) as u32,
+    );
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+
+    Ok(())
+}
+
+fn create(path: &Path) -> anyhow::Result<File> {
+    File::create(path).with_context(|| format!("Unable to create {}", path.display()))
+}
+
+struct LastFrame(Option<Frame>);
+
+impl FrameSink for LastFrame {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()> {
+        self.0 = Some(frame);
+        ControlFlow::Continue(())
+    }
+}
+
+// Size of the biggest frame and every symbol in any of them, which a GIF needs up front
+#[derive(Default)]
+struct Measure {
+    frames: usize,
+    width: usize,
+    height: usize,
+    symbols: BTreeSet<char>,
+}
+
+impl FrameSink for Measure {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()> {
+        self.frames += 1;
+        self.width = self.width.max(frame.cells.width());
+        self.height = self.height.max(frame.cells.height());
+        self.symbols
+            .extend(frame.cells.iter().map(|(_, &cell)| cell));
+
+        ControlFlow::Continue(())
+    }
+}
+
+struct GifSink {
+    encoder: gif::Encoder<BufWriter<File>>,
+    size: (usize, usize),
+    // Index of the color of each symbol in the palette, the background being 0
+    symbols: Vec<char>,
+    delay: u16,
+    error: Option<anyhow::Error>,
+}
+
+impl GifSink {
+    fn create(path: &Path, measure: &Measure, fps: f64) -> anyhow::Result<Self> {
+        let symbols: Vec<char> = measure.symbols.iter().copied().collect();
+        anyhow::ensure!(symbols.len() < 256, "GIFs can't have more than 256 colors");
+
+        let pixels = |cells: usize| u16::try_from(cells * CELL_SIZE);
+        let (Ok(width), Ok(height)) = (pixels(measure.width), pixels(measure.height)) else {
+            bail!("Frames are too big for a GIF");
+        };
+
+        let palette: Vec<u8> = [BACKGROUND]
+            .into_iter()
+            .chain(symbols.iter().map(|&symbol| color(symbol)))
+            .flatten()
+            .collect();
+
+        let mut encoder =
+            gif::Encoder::new(BufWriter::new(create(path)?), width, height, &palette)?;
+        encoder.set_repeat(gif::Repeat::Infinite)?;
+
+        Ok(GifSink {
+            encoder,
+            size: (measure.width, measure.height),
+            symbols,
+            // In hundredths of a second, and viewers ignore delays under 2
+            delay: (100.0 / fps).round().clamp(2.0, f64::from(u16::MAX)) as u16,
+            error: None,
+        })
+    }
+
+    fn index(&self, symbol: char) -> u8 {
+        let index = self.symbols.binary_search(&symbol).unwrap_or_default();
+        (index + 1) as u8
+    }
+}
+
+impl FrameSink for GifSink {
+    fn show(&mut self, frame: Frame) -> ControlFlow<()> {
+        let pixels = rasterize(&frame.cells, self.size, |cell| self.index(cell), 0);
+
+        let mut gif_frame = gif::Frame {
+            width: (self.size.0 * CELL_SIZE) as u16,
+            height: (self.size.1 * CELL_SIZE) as u16,
+            delay: self.delay,
+            ..gif::Frame::default()
+        };
+        gif_frame.buffer = pixels.into();
+
+        match self.encoder.write_frame(&gif_frame) {
+            Ok(()) => ControlFlow::Continue(()),
+            Err(error) => {
+                self.error = Some(error.into());
+                ControlFlow::Break(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::{Answer, Input};
+
+    // Grows a line of # by one cell each frame
+    struct Growing;
+
+    impl Solution for Growing {
+        fn parse(_input: &Input) -> anyhow::Result<Self> {
+            Ok(Growing)
+        }
+
+        fn part1(&self) -> anyhow::Result<Answer> {
+            Ok(Answer::from(3_i64))
+        }
+
+        fn part2(&self) -> anyhow::Result<Answer> {
+            self.part1()
+        }
+
+        fn animate(&self, _part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+            for length in 1..=3 {
+                let _ = sink.show(Frame::new("", Grid::new(length, 1, '#')));
+            }
+
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn exports_last_frame_as_png() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("line.png");
+        export(&Growing, Part::One, &path, 10.0).unwrap();
+
+        let decoder = png::Decoder::new(File::open(&path).unwrap());
+        let reader = decoder.read_info().unwrap();
+        let info = reader.info();
+        assert_eq!((info.width, info.height), (12, 4));
+    }
+
+    #[test]
+    fn exports_every_frame_as_gif() {
+        let directory = tempfile::tempdir().unwrap();
+        let path = directory.path().join("line.gif");
+        export(&Growing, Part::One, &path, 20.0).unwrap();
+
+        let mut options = gif::DecodeOptions::new();
+        options.set_color_output(gif::ColorOutput::Indexed);
+        let mut decoder = options.read_info(File::open(&path).unwrap()).unwrap();
+        assert_eq!((decoder.width(), decoder.height()), (12, 4));
+
+        let mut frames = vec![];
+        while let Some(frame) = decoder.read_next_frame().unwrap() {
+            frames.push((frame.delay, frame.buffer.to_vec()));
+        }
+
+        assert_eq!(frames.len(), 3);
+        assert_eq!(frames[0].0, 5);
+        // The first frame only has one cell of #, and the background after it
+        assert_eq!(&frames[0].1[..8], &[1, 1, 1, 1, 0, 0, 0, 0]);
+    }
+
+    #[test]
+    fn refuses_other_formats() {
+        let error = export(&Growing, Part::One, Path::new("line.jpg"), 10.0).unwrap_err();
+        assert!(error.to_string().contains(".png or .gif"));
+    }
+
+    #[test]
+    fn adds_part_to_paths() {
+        let path = path_for_part(Path::new("out/sand.gif"), Part::Two);
+        assert_eq!(path, Path::new("out/sand-part2.gif"));
+    }
+
+    #[test]
+    fn colors_digits_as_a_gradient() {
+        assert_eq!(color('0'), [30, 40, 120]);
+        assert_eq!(color('9'), [250, 230, 60]);
+        assert!(color('5')[0] > color('4')[0]);
+    }
+}
@@ -0,0 +1,150 @@
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Context;
+
+use crate::client::Client;
+use crate::days;
+
+/**
+ * Downloads puzzle inputs into the `input.txt` file of each day's crate, which is where everything
+ * else reads them from. That file doubles as the cache: a day that already has one is never
+ * downloaded again, so cached days work offline and without a session.
+ */
+pub struct InputFetcher {
+    root: PathBuf,
+    client: Option<Client>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+impl InputFetcher {
+    /// `root` is the root of the workspace, where the day crates are.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        InputFetcher {
+            root: root.into(),
+            client: None,
+        }
+    }
+
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.root.join(days::directory(day)).join("input.txt")
+    }
+
+    pub fn is_cached(&self, day: u8) -> bool {
+        self.path(day).exists()
+    }
+
+    pub fn fetch(&mut self, day: u8) -> anyhow::Result<Fetched> {
+        let path = self.path(day);
+
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let client = self
+            .client
+            .as_mut()
+            .with_context(|| format!("Input for day {} is not cached", day))?;
+        let input = client.get(&format!("/day/{}/input", day))?;
+
+        if let Some(directory) = path.parent() {
+            fs::create_dir_all(directory)?;
+        }
+        fs::write(&path, input).with_context(|| format!("Unable to write {}", path.display()))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::*;
+    use crate::client::mock_server::MockServer;
+
+    fn fetcher(server: &MockServer, root: &tempfile::TempDir) -> InputFetcher {
+        let client = Client::new(server.url(), "secret").with_min_delay(Duration::ZERO);
+        InputFetcher::new(root.path()).with_client(client)
+    }
+
+    #[test]
+    fn downloads_and_caches_input() {
+        let server = MockServer::start(vec![(200, "1000\n2000\n")]);
+        let root = tempfile::tempdir().unwrap();
+        let mut fetcher = fetcher(&server, &root);
+
+        let path = root.path().join("day01").join("input.txt");
+        assert_eq!(fetcher.fetch(1).unwrap(), Fetched::Downloaded(path.clone()));
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1000\n2000\n");
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "GET");
+        assert_eq!(requests[0].path, "/2022/day/1/input");
+        assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+    }
+
+    #[test]
+    fn never_downloads_cached_input() {
+        let server = MockServer::start(vec![(200, "new input")]);
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("day07").join("input.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "old input").unwrap();
+
+        assert_eq!(
+            fetcher(&server, &root).fetch(7).unwrap(),
+            Fetched::Cached(path.clone())
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), "old input");
+        assert!(server.requests().is_empty());
+    }
+
+    #[test]
+    fn cached_input_needs_no_client() {
+        let root = tempfile::tempdir().unwrap();
+        let path = root.path().join("day03").join("input.txt");
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "input").unwrap();
+
+        let mut fetcher = InputFetcher::new(root.path());
+        assert_eq!(fetcher.fetch(3).unwrap(), Fetched::Cached(path));
+        assert!(fetcher.fetch(4).is_err());
+    }
+
+    #[test]
+    fn waits_between_requests() {
+        let server = MockServer::start(vec![(200, "first"), (200, "second")]);
+        let root = tempfile::tempdir().unwrap();
+        let client = Client::new(server.url(), "secret").with_min_delay(Duration::from_millis(300));
+        let mut fetcher = InputFetcher::new(root.path()).with_client(client);
+
+        let start = Instant::now();
+        fetcher.fetch(1).unwrap();
+        fetcher.fetch(2).unwrap();
+
+        assert!(start.elapsed() >= Duration::from_millis(300));
+        assert_eq!(server.requests().len(), 2);
+    }
+
+    #[test]
+    fn does_not_cache_failed_downloads() {
+        let server = MockServer::start(vec![(400, "Please log in")]);
+        let root = tempfile::tempdir().unwrap();
+
+        let error = fetcher(&server, &root).fetch(1).unwrap_err();
+        assert!(error.to_string().contains("400"));
+        assert!(!root.path().join("day01").join("input.txt").exists());
+    }
+}
@@ -0,0 +1,318 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use animate::AnimateOptions;
+use anyhow::Context;
+use aoc_common::{init_logging, Input, Part, Unsolved};
+use bench::BenchOptions;
+use clap::{Parser, Subcommand};
+use client::Client;
+use days::Day;
+use fetch::{Fetched, InputFetcher};
+use output::Format;
+use submit::{Submitter, Verdict};
+use watch::WatchOptions;
+
+mod animate;
+mod bench;
+mod client;
+mod days;
+mod export;
+mod fetch;
+mod memory;
+mod output;
+mod scaffold;
+mod submit;
+mod table;
+mod verify;
+mod watch;
+
+/**
+ * Single entry point for every day of the challenge, so that tooling can drive all of them the
+ * same way instead of knowing how each day's binary behaves.
+ */
+#[derive(Parser)]
+#[command(name = "aoc")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// What to log to stderr, e.g. `debug` or `day14=trace` [default: RUST_LOG, or warnings]
+    #[arg(long, global = true)]
+    log: Option<String>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a day and print its answers
+    Run {
+        /// Day to run
+        day: u8,
+        /// Input file, `-` reads from stdin [default: dayNN/input.txt]
+        #[arg(long)]
+        input: Option<PathBuf>,
+        /// Only solve the given part
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// How to print the answers
+        #[arg(long, value_enum, default_value_t = Format::Text)]
+        format: Format,
+        /// Play the simulation behind each part in the terminal before printing its answer
+        #[arg(long)]
+        animate: bool,
+        /// Frames per second to start the animation at, or to play an exported GIF at
+        #[arg(long, default_value_t = 10.0, value_parser = parse_fps)]
+        fps: f64,
+        /// Write the frames of the animation to files in this directory instead of playing them
+        #[arg(long, requires = "animate")]
+        headless: Option<PathBuf>,
+        /// Save the animation as an animated GIF, or its last frame as a PNG, depending on the
+        /// extension. With both parts, each one gets its own file, e.g. `out-part1.gif`
+        #[arg(long)]
+        export: Option<PathBuf>,
+    },
+    /// Check that the answers for the sample and real input match each day's answers.toml
+    Verify {
+        /// Days to verify [default: all of them]
+        days: Vec<u8>,
+    },
+    /// Download the input of the given days, unless they were downloaded already
+    Fetch {
+        /// Days to download
+        #[arg(required = true)]
+        days: Vec<u8>,
+    },
+    /// Solve a part of a day with its real input and submit the answer
+    Submit {
+        /// Day to submit
+        day: u8,
+        /// Part to submit
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+    },
+    /// Create the crate for a new day and register it in the workspace and the runner
+    New {
+        /// Day to create
+        day: u8,
+    },
+    /// Time parsing and solving each part on the real input
+    Bench {
+        /// Days to benchmark [default: all of them]
+        days: Vec<u8>,
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10)]
+        iterations: usize,
+        /// Save the results as JSON, to be used as a baseline later
+        #[arg(long)]
+        save: Option<PathBuf>,
+        /// Compare against results saved by a previous run
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+        /// Percentage by which the median can get slower than the baseline
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+    },
+    /// Rebuild and re-run a day whenever its code or inputs change, comparing the answers
+    Watch {
+        /// Day to watch
+        day: u8,
+        /// Build and run in release mode
+        #[arg(long)]
+        release: bool,
+    },
+    /// Count allocations and peak memory of parsing and each part, needs `--features alloc-stats`
+    Memory {
+        /// Days to measure [default: all of them]
+        days: Vec<u8>,
+    },
+}
+
+fn main() -> anyhow::Result<()> {
+    let cli = Cli::parse();
+    init_logging(cli.log.as_deref())?;
+
+    match cli.command {
+        Command::Run {
+            day,
+            input,
+            part,
+            format,
+            animate,
+            fps,
+            headless,
+            export,
+        } => {
+            let options = RunOptions {
+                format,
+                animate: animate.then_some(AnimateOptions { fps, headless }),
+                export,
+                fps,
+            };
+            run(day, input, part, &options)
+        }
+        Command::Verify { days } => verify::verify(&find_days(&days)?),
+        Command::Fetch { days } => fetch(&days),
+        Command::Submit { day, part } => submit(day, part),
+        Command::New { day } => new(day),
+        Command::Bench {
+            days,
+            iterations,
+            save,
+            baseline,
+            threshold,
+        } => bench::bench(
+            &find_days(&days)?,
+            &BenchOptions {
+                iterations,
+                save,
+                baseline,
+                threshold,
+            },
+        ),
+        Command::Watch { day, release } => watch::watch(day, &WatchOptions { release }),
+        Command::Memory { days } => memory::memory(&find_days(&days)?),
+    }
+}
+
+fn parse_fps(text: &str) -> Result<f64, String> {
+    let fps: f64 = text
+        .parse()
+        .map_err(|_| format!("{} isn't a number", text))?;
+
+    if animate::FPS_RANGE.contains(&fps) {
+        Ok(fps)
+    } else {
+        Err(format!(
+            "The speed has to be between {} and {} frames per second",
+            animate::FPS_RANGE.start(),
+            animate::FPS_RANGE.end()
+        ))
+    }
+}
+
+// All days if none are given
+fn find_days(numbers: &[u8]) -> anyhow::Result<Vec<&'static Day>> {
+    if numbers.is_empty() {
+        return Ok(days::DAYS.iter().collect());
+    }
+
+    numbers.iter().map(|&number| find_day(number)).collect()
+}
+
+fn find_day(number: u8) -> anyhow::Result<&'static Day> {
+    days::find(number).with_context(|| format!("Day {} is not solved", number))
+}
+
+struct RunOptions {
+    format: Format,
+    animate: Option<AnimateOptions>,
+    export: Option<PathBuf>,
+    fps: f64,
+}
+
+fn run(
+    day: u8,
+    input: Option<PathBuf>,
+    part: Option<u8>,
+    options: &RunOptions,
+) -> anyhow::Result<()> {
+    let day = find_day(day)?;
+    let _span = tracing::info_span!("day", day = day.number).entered();
+    let path = input.unwrap_or_else(|| PathBuf::from(day.directory()).join("input.txt"));
+    let input = Input::load(path)?;
+
+    let parts = match part {
+        Some(part) => vec![Part::try_from(part)?],
+        None => Part::ALL.to_vec(),
+    };
+
+    let solution = day.parse(&input)?;
+
+    for &part in &parts {
+        if let Some(animate) = &options.animate {
+            animate::animate(solution.as_ref(), part, animate)?;
+        }
+
+        if let Some(path) = &options.export {
+            let path = match parts.len() {
+                1 => path.clone(),
+                _ => export::path_for_part(path, part),
+            };
+            export::export(solution.as_ref(), part, &path, options.fps)?;
+            eprintln!("Exported {} to {}", part, path.display());
+        }
+
+        let start = Instant::now();
+        let answer = match solution.solve(part) {
+            Ok(answer) => Some(answer),
+            Err(error) if error.is::<Unsolved>() => None,
+            Err(error) => return Err(error),
+        };
+        options
+            .format
+            .print(day.number, part, answer.as_ref(), start.elapsed());
+    }
+
+    Ok(())
+}
+
+fn fetch(days: &[u8]) -> anyhow::Result<()> {
+    let mut fetcher = InputFetcher::new(".");
+
+    // Cached inputs don't need a session, so only require one if something is missing
+    if days.iter().any(|&day| !fetcher.is_cached(day)) {
+        fetcher = fetcher.with_client(Client::from_env()?);
+    }
+
+    for &day in days {
+        match fetcher.fetch(day)? {
+            Fetched::Cached(path) => println!("Day {}: already in {}", day, path.display()),
+            Fetched::Downloaded(path) => println!("Day {}: downloaded to {}", day, path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(day: u8, part: u8) -> anyhow::Result<()> {
+    let part = Part::try_from(part)?;
+    let solution = find_day(day)?;
+    let input = Input::from_path(PathBuf::from(solution.directory()).join("input.txt"))?;
+    let answer = solution.parse(&input)?.solve(part)?;
+
+    anyhow::ensure!(
+        !answer.is_rendering(),
+        "{} of day {} has to be read and submitted by hand:\n{}",
+        part,
+        day,
+        answer
+    );
+
+    let mut submitter = Submitter::new(".");
+    // Don't ask for a session just to refuse the answer
+    submitter.history(day)?.check(part, &answer)?;
+    submitter = submitter.with_client(Client::from_env()?);
+
+    println!("Submitting {} for day {}, {}", answer, day, part);
+    let verdict = submitter.submit(day, part, &answer)?;
+    println!("{}: {}", answer, verdict);
+
+    anyhow::ensure!(
+        matches!(verdict, Verdict::Correct | Verdict::AlreadySolved),
+        "{} was not accepted",
+        answer
+    );
+
+    Ok(())
+}
+
+fn new(day: u8) -> anyhow::Result<()> {
+    let directory = scaffold::scaffold(Path::new("."), day)?;
+
+    println!("Created {}", directory.display());
+    println!(
+        "Run `cargo run -p aoc -- fetch {}` to download its input",
+        day
+    );
+
+    Ok(())
+}
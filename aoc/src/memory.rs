@@ -0,0 +1,209 @@
+/*!
+ * Counts what the days allocate, to find the ones that churn through memory.
+ *
+ * Counting needs to replace the global allocator, which slows down every allocation a little, so
+ * it's only installed when the runner is built with the `alloc-stats` feature:
+ *
+ * ```text
+ * cargo run --release -p aoc --features alloc-stats -- memory
+ * ```
+ */
+#[cfg(feature = "alloc-stats")]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aoc_common::{Input, InputFile, Part};
+
+use crate::bench::Step;
+use crate::days::Day;
+use crate::table::print_table;
+
+#[cfg(feature = "alloc-stats")]
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+/// Whether allocations are being counted at all, i.e. whether the runner was built with the feature.
+pub const ENABLED: bool = cfg!(feature = "alloc-stats");
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static ALLOCATED: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, keeping count of allocations and of how many bytes are in use.
+#[cfg(feature = "alloc-stats")]
+pub struct CountingAllocator;
+
+#[cfg(feature = "alloc-stats")]
+impl CountingAllocator {
+    fn allocated(size: usize) {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        ALLOCATED.fetch_add(size, Ordering::Relaxed);
+        let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+        PEAK.fetch_max(live, Ordering::Relaxed);
+    }
+
+    fn freed(size: usize) {
+        LIVE.fetch_sub(size, Ordering::Relaxed);
+    }
+}
+
+#[cfg(feature = "alloc-stats")]
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc(layout);
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let pointer = System.alloc_zeroed(layout);
+        if !pointer.is_null() {
+            Self::allocated(layout.size());
+        }
+        pointer
+    }
+
+    unsafe fn dealloc(&self, pointer: *mut u8, layout: Layout) {
+        System.dealloc(pointer, layout);
+        Self::freed(layout.size());
+    }
+
+    // Growing a Vec or a HashMap counts as allocating its new size, since that's what it costs
+    // when the memory can't be grown in place
+    unsafe fn realloc(&self, pointer: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_pointer = System.realloc(pointer, layout, new_size);
+        if !new_pointer.is_null() {
+            Self::freed(layout.size());
+            Self::allocated(new_size);
+        }
+        new_pointer
+    }
+}
+
+/// What was allocated while running some code.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Usage {
+    pub allocations: usize,
+    /// Total of every allocation, including those freed since
+    pub bytes: usize,
+    /// Most bytes in use at once, on top of what was in use already
+    pub peak: usize,
+}
+
+/// Runs `f`, counting what it allocates. Everything is 0 unless `ENABLED`.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Usage) {
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+    let allocations = ALLOCATIONS.load(Ordering::Relaxed);
+    let bytes = ALLOCATED.load(Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        allocations: ALLOCATIONS.load(Ordering::Relaxed) - allocations,
+        bytes: ALLOCATED.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+
+    (result, usage)
+}
+
+/**
+ * Prints the allocations, bytes allocated and peak memory of parsing and solving each part of the
+ * given days on their real input, with the days that allocate the most at their peak first.
+ *
+ * The peak of a part doesn't include the parsed input it starts from, which is the peak of parsing.
+ */
+pub fn memory(days: &[&Day]) -> anyhow::Result<()> {
+    anyhow::ensure!(
+        ENABLED,
+        "Allocations aren't counted, run again with --features alloc-stats"
+    );
+
+    let mut results = vec![];
+
+    for day in days {
+        let path = Path::new(&day.directory()).join(InputFile::Real.file_name());
+        let input = Input::from_path(path)?;
+
+        let (solution, usage) = measure(|| day.parse(&input));
+        results.push((day.number, Step::Parse, usage));
+        let solution = solution?;
+
+        for part in Part::ALL {
+            let (answer, usage) = measure(|| solution.solve(part));
+            // Parts that aren't solved yet are left out, like when benchmarking
+            if answer.is_ok() {
+                results.push((day.number, part.into(), usage));
+            }
+        }
+    }
+
+    results.sort_by_key(|&(_, _, usage)| std::cmp::Reverse(usage.peak));
+
+    let rows: Vec<[String; 5]> = results
+        .iter()
+        .map(|(day, step, usage)| {
+            [
+                day.to_string(),
+                step.to_string(),
+                usage.allocations.to_string(),
+                format_bytes(usage.bytes),
+                format_bytes(usage.peak),
+            ]
+        })
+        .collect();
+
+    print_table(["Day", "Step", "Allocations", "Allocated", "Peak"], &rows);
+
+    Ok(())
+}
+
+fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{}B", bytes),
+        _ => format!("{:.2}{}", size, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes_in_binary_units() {
+        assert_eq!(format_bytes(0), "0B");
+        assert_eq!(format_bytes(1023), "1023B");
+        assert_eq!(format_bytes(1536), "1.50KiB");
+        assert_eq!(format_bytes(3 * 1024 * 1024), "3.00MiB");
+    }
+
+    #[test]
+    #[cfg(feature = "alloc-stats")]
+    fn counts_allocations_and_peak() {
+        let (_, usage) = measure(|| {
+            let first = vec![0_u8; 1000];
+            drop(first);
+            let second = vec![0_u8; 600];
+            second.len()
+        });
+
+        // Tests run in parallel, so other threads may have allocated meanwhile
+        assert!(usage.allocations >= 2);
+        assert!(usage.bytes >= 1600);
+        assert!(usage.peak >= 1000);
+    }
+}
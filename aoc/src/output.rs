@@ -0,0 +1,78 @@
+use std::time::Duration;
+
+use aoc_common::{Answer, Part};
+use clap::ValueEnum;
+use serde::Serialize;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    /// `Part N: answer`, the same as each day's own binary
+    Text,
+    /// One JSON object per line for each part
+    Json,
+}
+
+/// Result of solving a single part, as printed in JSON. `elapsed_ns` doesn't include parsing, and
+/// `answer` is null for parts that aren't solved yet.
+#[derive(Debug, Serialize)]
+pub struct PartResult<'a> {
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<&'a Answer>,
+    pub elapsed_ns: u64,
+}
+
+impl Format {
+    pub fn print(&self, day: u8, part: Part, answer: Option<&Answer>, elapsed: Duration) {
+        match self {
+            Format::Text => match answer {
+                Some(answer) => aoc_common::print_answer(part, answer),
+                None => aoc_common::print_unsolved(part),
+            },
+            Format::Json => {
+                let result = PartResult {
+                    day,
+                    part: part.number(),
+                    answer,
+                    elapsed_ns: u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX),
+                };
+                println!("{}", serde_json::to_string(&result).unwrap());
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn to_json(answer: Option<&Answer>) -> String {
+        let result = PartResult {
+            day: 10,
+            part: 2,
+            answer,
+            elapsed_ns: 1500,
+        };
+        serde_json::to_string(&result).unwrap()
+    }
+
+    #[test]
+    fn serializes_every_kind_of_answer() {
+        assert_eq!(
+            to_json(Some(&Answer::from(13140_i64))),
+            r#"{"day":10,"part":2,"answer":13140,"elapsed_ns":1500}"#
+        );
+        assert_eq!(
+            to_json(Some(&Answer::from("CMZ"))),
+            r#"{"day":10,"part":2,"answer":"CMZ","elapsed_ns":1500}"#
+        );
+        assert_eq!(
+            to_json(Some(&Answer::Rendering("##..\n#..#".into()))),
+            r###"{"day":10,"part":2,"answer":"##..\n#..#","elapsed_ns":1500}"###
+        );
+        assert_eq!(
+            to_json(None),
+            r#"{"day":10,"part":2,"answer":null,"elapsed_ns":1500}"#
+        );
+    }
+}
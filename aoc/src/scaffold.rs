@@ -0,0 +1,180 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{ensure, Context};
+
+use crate::days;
+
+const TEMPLATES: [(&str, &str); 5] = [
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("README.md", include_str!("../templates/README.md.tmpl")),
+    (
+        "answers.toml",
+        include_str!("../templates/answers.toml.tmpl"),
+    ),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+];
+
+/**
+ * Creates the crate for a new day from the templates in `aoc/templates`, with a `Solution` whose
+ * parts aren't solved yet, an empty `sample.txt` and an `answers.toml` without answers.
+ *
+ * The crate is then registered everywhere the workspace lists days: as a member of the workspace,
+ * as a dependency of the runner and in the runner's `DAYS`.
+ */
+pub fn scaffold(root: &Path, day: u8) -> anyhow::Result<PathBuf> {
+    ensure!((1..=25).contains(&day), "Invalid day {}", day);
+
+    let name = days::directory(day);
+    let directory = root.join(&name);
+    ensure!(
+        !directory.exists(),
+        "{} already exists",
+        directory.display()
+    );
+
+    for (file, template) in TEMPLATES {
+        let path = directory.join(file);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        write(&path, &render(template, day))?;
+    }
+    write(&directory.join("sample.txt"), "")?;
+
+    register(
+        &root.join("Cargo.toml"),
+        &format!("    \"{}\",", name),
+        |line| line.starts_with("\"day"),
+    )?;
+    register(
+        &root.join("aoc").join("Cargo.toml"),
+        &format!("{} = {{ path = \"../{}\" }}", name, name),
+        |line| line.starts_with("day"),
+    )?;
+    register(
+        &root.join("aoc").join("src").join("days.rs"),
+        &format!("    Day::new::<{}::Day{:02}>({}),", name, day, day),
+        |line| line.starts_with("Day::new::<day"),
+    )?;
+
+    Ok(directory)
+}
+
+fn render(template: &str, day: u8) -> String {
+    template
+        .replace("{{padded}}", &format!("{:02}", day))
+        .replace("{{day}}", &day.to_string())
+}
+
+fn write(path: &Path, contents: &str) -> anyhow::Result<()> {
+    fs::write(path, contents).with_context(|| format!("Unable to write {}", path.display()))
+}
+
+/**
+ * Adds `entry` as a line of the file at `path`, among the lines for which `is_entry` is true
+ * (ignoring indentation). Those are kept sorted, which zero-padded day numbers make alphabetical.
+ */
+fn register(path: &Path, entry: &str, is_entry: impl Fn(&str) -> bool) -> anyhow::Result<()> {
+    let text =
+        fs::read_to_string(path).with_context(|| format!("Unable to read {}", path.display()))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let entries: Vec<usize> = (0..lines.len())
+        .filter(|&index| is_entry(lines[index].trim_start()))
+        .collect();
+    let last = *entries.last().with_context(|| {
+        format!(
+            "Unable to find where to register days in {}",
+            path.display()
+        )
+    })?;
+    ensure!(
+        !entries.iter().any(|&index| lines[index] == entry),
+        "{} is already registered in {}",
+        entry.trim(),
+        path.display()
+    );
+
+    let position = entries
+        .into_iter()
+        .find(|&index| lines[index].trim_start() > entry.trim_start())
+        .unwrap_or(last + 1);
+    lines.insert(position, entry);
+
+    write(path, &(lines.join("\n") + "\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Copies of the real files that list days, so the tests register days the way it happens in
+    // the actual workspace
+    fn workspace() -> tempfile::TempDir {
+        let root = tempfile::tempdir().unwrap();
+        let source = Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+        for file in ["Cargo.toml", "aoc/Cargo.toml", "aoc/src/days.rs"] {
+            let path = root.path().join(file);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::copy(source.join(file), path).unwrap();
+        }
+
+        root
+    }
+
+    fn read(root: &tempfile::TempDir, file: &str) -> String {
+        fs::read_to_string(root.path().join(file)).unwrap()
+    }
+
+    #[test]
+    fn creates_crate_from_templates() {
+        let root = workspace();
+        let directory = scaffold(root.path(), 18).unwrap();
+
+        assert_eq!(directory, root.path().join("day18"));
+        assert!(read(&root, "day18/Cargo.toml").contains("name = \"day18\""));
+        assert!(read(&root, "day18/README.md").starts_with("# Day 18\n"));
+        assert!(read(&root, "day18/src/lib.rs").contains("impl Solution for Day18 {"));
+        assert!(read(&root, "day18/src/main.rs").contains("run::<day18::Day18>()"));
+        assert_eq!(read(&root, "day18/sample.txt"), "");
+        assert!(!directory.join("input.txt").exists());
+
+        let manifest = aoc_common::AnswerManifest::from_directory(&directory).unwrap();
+        assert_eq!(manifest, aoc_common::AnswerManifest::default());
+    }
+
+    #[test]
+    fn registers_day_in_order() {
+        let root = workspace();
+        scaffold(root.path(), 16).unwrap();
+
+        assert!(
+            read(&root, "Cargo.toml").contains("    \"day15\",\n    \"day16\",\n    \"day17\",\n")
+        );
+        assert!(read(&root, "aoc/Cargo.toml")
+            .contains("day15 = { path = \"../day15\" }\nday16 = { path = \"../day16\" }\nday17 ="));
+        assert!(read(&root, "aoc/src/days.rs").contains(
+            "    Day::new::<day15::Day15>(15),\n    Day::new::<day16::Day16>(16),\n    Day::new::<day17::Day17>(17),\n"
+        ));
+    }
+
+    #[test]
+    fn registers_day_after_the_last_one() {
+        let root = workspace();
+        scaffold(root.path(), 25).unwrap();
+
+        assert!(read(&root, "aoc/src/days.rs").contains("    Day::new::<day25::Day25>(25),\n];\n"));
+    }
+
+    #[test]
+    fn refuses_existing_days() {
+        let root = workspace();
+        scaffold(root.path(), 18).unwrap();
+
+        assert!(scaffold(root.path(), 18).is_err());
+        assert!(scaffold(root.path(), 26).is_err());
+    }
+}
@@ -0,0 +1,404 @@
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use anyhow::{bail, Context};
+use aoc_common::{Answer, Part};
+use serde::{Deserialize, Serialize};
+
+use crate::client::Client;
+use crate::days;
+
+/**
+ * Submits answers to the website, keeping every submission and its verdict in the
+ * `submissions.json` file of each day's crate.
+ *
+ * That history is checked before anything is sent, so answers that are already known to be wrong
+ * are refused locally instead of costing another wrong attempt (and the wait that comes with it).
+ * Integer answers are also checked against the bounds given by previous "too high" and "too low"
+ * verdicts.
+ */
+pub struct Submitter {
+    root: PathBuf,
+    client: Option<Client>,
+}
+
+impl Submitter {
+    /// `root` is the root of the workspace, where the day crates are.
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Submitter {
+            root: root.into(),
+            client: None,
+        }
+    }
+
+    pub fn with_client(mut self, client: Client) -> Self {
+        self.client = Some(client);
+        self
+    }
+
+    pub fn history_path(&self, day: u8) -> PathBuf {
+        self.root
+            .join(days::directory(day))
+            .join("submissions.json")
+    }
+
+    pub fn history(&self, day: u8) -> anyhow::Result<History> {
+        History::from_path(&self.history_path(day))
+    }
+
+    pub fn submit(&mut self, day: u8, part: Part, answer: &Answer) -> anyhow::Result<Verdict> {
+        let mut history = self.history(day)?;
+        history.check(part, answer)?;
+
+        let client = self
+            .client
+            .as_mut()
+            .context("A session is needed to submit answers")?;
+        let level = part.number().to_string();
+        let response = client.post_form(
+            &format!("/day/{}/answer", day),
+            &[("level", &level), ("answer", &answer.to_string())],
+        )?;
+        let verdict = Verdict::from_response(&response)?;
+
+        history.record(part, answer.clone(), verdict);
+        history.save(&self.history_path(day))?;
+
+        Ok(verdict)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without saying in which direction
+    Incorrect,
+    /// Nothing was checked, the answer can be submitted again after waiting
+    RateLimited {
+        wait_secs: u64,
+    },
+    /// The part was already solved from some other place
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict from the page the website answers a submission with.
+    pub fn from_response(html: &str) -> anyhow::Result<Self> {
+        let text = html
+            .split_once("<article>")
+            .and_then(|(_, rest)| rest.split_once("</article>"))
+            .map_or(html, |(article, _)| article);
+
+        if text.contains("That's the right answer") {
+            Ok(Verdict::Correct)
+        } else if text.contains("your answer is too high") {
+            Ok(Verdict::TooHigh)
+        } else if text.contains("your answer is too low") {
+            Ok(Verdict::TooLow)
+        } else if text.contains("That's not the right answer") {
+            Ok(Verdict::Incorrect)
+        } else if text.contains("You gave an answer too recently") {
+            Ok(Verdict::RateLimited {
+                wait_secs: parse_wait(text).unwrap_or(60),
+            })
+        } else if text.contains("Did you already complete it") {
+            Ok(Verdict::AlreadySolved)
+        } else {
+            bail!("Unexpected response to submission: {}", text.trim())
+        }
+    }
+
+    pub fn is_wrong(&self) -> bool {
+        matches!(
+            self,
+            Verdict::TooHigh | Verdict::TooLow | Verdict::Incorrect
+        )
+    }
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "too high"),
+            Verdict::TooLow => write!(f, "too low"),
+            Verdict::Incorrect => write!(f, "incorrect"),
+            Verdict::RateLimited { wait_secs } => {
+                write!(f, "rate limited, wait {}s before trying again", wait_secs)
+            }
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+// Reads the time left from e.g. "You have 1m 23s left to wait."
+fn parse_wait(text: &str) -> Option<u64> {
+    let (before, _) = text.split_once(" left to wait")?;
+    let (_, wait) = before.rsplit_once("have ")?;
+
+    wait.split_whitespace()
+        .map(|amount| {
+            let (number, unit) = amount.split_at(amount.find(|c: char| !c.is_ascii_digit())?);
+            let number: u64 = number.parse().ok()?;
+
+            match unit {
+                "h" => Some(number * 3600),
+                "m" => Some(number * 60),
+                "s" => Some(number),
+                _ => None,
+            }
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub part: u8,
+    pub answer: Answer,
+    pub verdict: Verdict,
+    /// Seconds since the Unix epoch
+    pub submitted_at: u64,
+}
+
+/// Every answer submitted for a single day, oldest first.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct History {
+    pub submissions: Vec<Submission>,
+}
+
+impl History {
+    /// A missing file is an empty history, since nothing was submitted yet.
+    fn from_path(path: &Path) -> anyhow::Result<Self> {
+        if !path.exists() {
+            return Ok(History::default());
+        }
+
+        let text = fs::read_to_string(path)
+            .with_context(|| format!("Unable to read {}", path.display()))?;
+        serde_json::from_str(&text)
+            .with_context(|| format!("Invalid history in {}", path.display()))
+    }
+
+    fn save(&self, path: &Path) -> anyhow::Result<()> {
+        let json = serde_json::to_string_pretty(self)?;
+        fs::write(path, json).with_context(|| format!("Unable to write {}", path.display()))
+    }
+
+    fn record(&mut self, part: Part, answer: Answer, verdict: Verdict) {
+        let submitted_at = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap_or(Duration::ZERO)
+            .as_secs();
+
+        self.submissions.push(Submission {
+            part: part.number(),
+            answer,
+            verdict,
+            submitted_at,
+        });
+    }
+
+    fn for_part(&self, part: Part) -> impl Iterator<Item = &Submission> {
+        self.submissions
+            .iter()
+            .filter(move |submission| submission.part == part.number())
+    }
+
+    /// Fails if, judging by previous submissions, `answer` can't be right or there is no point in
+    /// submitting it.
+    pub fn check(&self, part: Part, answer: &Answer) -> anyhow::Result<()> {
+        if let Some(correct) = self
+            .for_part(part)
+            .find(|submission| submission.verdict == Verdict::Correct)
+        {
+            bail!("{} was already solved with {}", part, correct.answer);
+        }
+
+        if let Some(previous) = self
+            .for_part(part)
+            .find(|submission| submission.verdict.is_wrong() && &submission.answer == answer)
+        {
+            bail!(
+                "{} was already submitted and was {}",
+                answer,
+                previous.verdict
+            );
+        }
+
+        if let Answer::Integer(value) = answer {
+            let submitted = |verdict| {
+                self.for_part(part)
+                    .filter(move |submission| submission.verdict == verdict)
+                    .filter_map(|submission| match submission.answer {
+                        Answer::Integer(value) => Some(value),
+                        _ => None,
+                    })
+            };
+
+            if let Some(too_high) = submitted(Verdict::TooHigh).min() {
+                if *value >= too_high {
+                    bail!(
+                        "{} can't be right, {} was already too high",
+                        value,
+                        too_high
+                    );
+                }
+            }
+
+            if let Some(too_low) = submitted(Verdict::TooLow).max() {
+                if *value <= too_low {
+                    bail!("{} can't be right, {} was already too low", value, too_low);
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::mock_server::MockServer;
+
+    const CORRECT: &str =
+        "<main><article><p>That's the right answer! You are one gold star closer \
+        to collecting enough star fruit.</p></article></main>";
+    const TOO_HIGH: &str = "<main><article><p>That's not the right answer; your answer is too \
+        high. Please wait one minute before trying again.</p></article></main>";
+    const TOO_LOW: &str = "<main><article><p>That's not the right answer; your answer is too low. \
+        Please wait one minute before trying again.</p></article></main>";
+    const INCORRECT: &str = "<main><article><p>That's not the right answer. If you're stuck, make \
+        sure you're using the full input data.</p></article></main>";
+    const TOO_RECENT: &str = "<main><article><p>You gave an answer too recently; you have to wait \
+        after submitting an answer before trying again.  You have 1m 23s left to wait.</p>\
+        </article></main>";
+
+    fn submitter(server: &MockServer, root: &tempfile::TempDir) -> Submitter {
+        let client = Client::new(server.url(), "secret").with_min_delay(Duration::ZERO);
+        fs::create_dir_all(root.path().join("day01")).unwrap();
+        Submitter::new(root.path()).with_client(client)
+    }
+
+    #[test]
+    fn parses_responses() {
+        assert_eq!(Verdict::from_response(CORRECT).unwrap(), Verdict::Correct);
+        assert_eq!(Verdict::from_response(TOO_HIGH).unwrap(), Verdict::TooHigh);
+        assert_eq!(Verdict::from_response(TOO_LOW).unwrap(), Verdict::TooLow);
+        assert_eq!(
+            Verdict::from_response(INCORRECT).unwrap(),
+            Verdict::Incorrect
+        );
+        assert_eq!(
+            Verdict::from_response(TOO_RECENT).unwrap(),
+            Verdict::RateLimited { wait_secs: 83 }
+        );
+        assert!(Verdict::from_response("<article>Something else</article>").is_err());
+    }
+
+    #[test]
+    fn posts_answer_and_records_it() {
+        let server = MockServer::start(vec![(200, CORRECT)]);
+        let root = tempfile::tempdir().unwrap();
+        let mut submitter = submitter(&server, &root);
+
+        let verdict = submitter
+            .submit(1, Part::Two, &Answer::from(45000_i64))
+            .unwrap();
+        assert_eq!(verdict, Verdict::Correct);
+
+        let requests = server.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].method, "POST");
+        assert_eq!(requests[0].path, "/2022/day/1/answer");
+        assert_eq!(requests[0].body, "level=2&answer=45000");
+
+        let history = submitter.history(1).unwrap();
+        assert_eq!(history.submissions.len(), 1);
+        assert_eq!(history.submissions[0].part, 2);
+        assert_eq!(history.submissions[0].answer, Answer::from(45000_i64));
+        assert_eq!(history.submissions[0].verdict, Verdict::Correct);
+    }
+
+    #[test]
+    fn refuses_known_wrong_answers() {
+        let server = MockServer::start(vec![(200, INCORRECT)]);
+        let root = tempfile::tempdir().unwrap();
+        let mut submitter = submitter(&server, &root);
+
+        let answer = Answer::from("CMZ");
+        assert_eq!(
+            submitter.submit(1, Part::One, &answer).unwrap(),
+            Verdict::Incorrect
+        );
+        assert!(submitter.submit(1, Part::One, &answer).is_err());
+        assert_eq!(server.requests().len(), 1);
+    }
+
+    #[test]
+    fn refuses_answers_outside_bounds() {
+        let server = MockServer::start(vec![(200, TOO_HIGH), (200, TOO_LOW), (200, CORRECT)]);
+        let root = tempfile::tempdir().unwrap();
+        let mut submitter = submitter(&server, &root);
+
+        submitter
+            .submit(1, Part::One, &Answer::from(100_i64))
+            .unwrap();
+        submitter
+            .submit(1, Part::One, &Answer::from(10_i64))
+            .unwrap();
+
+        assert!(submitter
+            .submit(1, Part::One, &Answer::from(150_i64))
+            .is_err());
+        assert!(submitter
+            .submit(1, Part::One, &Answer::from(5_i64))
+            .is_err());
+        // Bounds are per part
+        submitter
+            .submit(1, Part::Two, &Answer::from(150_i64))
+            .unwrap();
+        assert_eq!(server.requests().len(), 3);
+    }
+
+    #[test]
+    fn allows_resubmitting_after_rate_limit() {
+        let server = MockServer::start(vec![(200, TOO_RECENT), (200, CORRECT)]);
+        let root = tempfile::tempdir().unwrap();
+        let mut submitter = submitter(&server, &root);
+
+        let answer = Answer::from(24000_i64);
+        assert_eq!(
+            submitter.submit(1, Part::One, &answer).unwrap(),
+            Verdict::RateLimited { wait_secs: 83 }
+        );
+        assert_eq!(
+            submitter.submit(1, Part::One, &answer).unwrap(),
+            Verdict::Correct
+        );
+        assert!(submitter.submit(1, Part::One, &answer).is_err());
+        assert_eq!(submitter.history(1).unwrap().submissions.len(), 2);
+    }
+
+    #[test]
+    fn history_needs_no_client() {
+        let root = tempfile::tempdir().unwrap();
+        let mut history = History::default();
+        history.record(Part::One, Answer::from(7_i64), Verdict::TooLow);
+        fs::create_dir_all(root.path().join("day03")).unwrap();
+        history
+            .save(&root.path().join("day03").join("submissions.json"))
+            .unwrap();
+
+        let mut submitter = Submitter::new(root.path());
+        let error = submitter
+            .submit(3, Part::One, &Answer::from(6_i64))
+            .unwrap_err();
+        assert!(error.to_string().contains("too low"));
+    }
+}
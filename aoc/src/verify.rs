@@ -0,0 +1,162 @@
+use std::path::Path;
+
+use aoc_common::{Answer, AnswerManifest, Input, InputFile, ParseError, Part};
+
+use crate::days::Day;
+use crate::table::print_table;
+
+/**
+ * Solves every part that has a known answer in a day's `answers.toml` and compares the result.
+ *
+ * Anything that gets in the way of producing an answer (a missing input file, a parse error, a part
+ * that fails) counts as a mismatch, since it means the recorded answer can't be reproduced anymore.
+ * Returns an error if any answer doesn't match, after printing a table with all of them.
+ */
+pub fn verify(days: &[&Day]) -> anyhow::Result<()> {
+    let mut checked = 0;
+    let mut mismatches = vec![];
+
+    for day in days {
+        let manifest = match AnswerManifest::from_directory(day.directory()) {
+            Ok(manifest) => manifest,
+            Err(error) => {
+                mismatches.push(Mismatch::without_answer(day.number, error));
+                continue;
+            }
+        };
+
+        for file in InputFile::ALL {
+            let expected = manifest.expected(file);
+            let parts: Vec<(Part, &Answer)> = Part::ALL
+                .into_iter()
+                .filter_map(|part| expected.get(part).map(|answer| (part, answer)))
+                .collect();
+
+            if parts.is_empty() {
+                continue;
+            }
+
+            checked += parts.len();
+
+            let path = Path::new(&day.directory()).join(file.file_name());
+            let _span =
+                tracing::info_span!("input", day = day.number, file = %path.display()).entered();
+            let solution = Input::from_path(path).and_then(|input| day.parse(&input));
+
+            for (part, expected) in parts {
+                let actual = match &solution {
+                    Ok(solution) => solution.solve(part).map_err(|error| error_cell(&error)),
+                    Err(error) => Err(error_cell(error)),
+                };
+
+                if actual.as_ref() != Ok(expected) {
+                    mismatches.push(Mismatch {
+                        day: day.number,
+                        file: Some(file),
+                        part: Some(part),
+                        expected: Some(expected.clone()),
+                        actual,
+                    });
+                }
+            }
+        }
+    }
+
+    if mismatches.is_empty() {
+        println!("All {} answers match", checked);
+        return Ok(());
+    }
+
+    print_mismatches(&mismatches);
+    anyhow::bail!("Found {} mismatches", mismatches.len())
+}
+
+struct Mismatch {
+    day: u8,
+    file: Option<InputFile>,
+    part: Option<Part>,
+    expected: Option<Answer>,
+    actual: Result<Answer, String>,
+}
+
+impl Mismatch {
+    // For problems that affect a whole day, rather than a specific answer
+    fn without_answer(day: u8, error: anyhow::Error) -> Self {
+        Mismatch {
+            day,
+            file: None,
+            part: None,
+            expected: None,
+            actual: Err(error_cell(&error)),
+        }
+    }
+}
+
+fn print_mismatches(mismatches: &[Mismatch]) {
+    let rows: Vec<[String; 5]> = mismatches
+        .iter()
+        .map(|mismatch| {
+            [
+                mismatch.day.to_string(),
+                optional_cell(mismatch.file),
+                optional_cell(mismatch.part.map(|part| part.number())),
+                optional_cell(mismatch.expected.as_ref().map(answer_cell)),
+                match &mismatch.actual {
+                    Ok(answer) => answer_cell(answer),
+                    Err(error) => format!("error: {}", error),
+                },
+            ]
+        })
+        .collect();
+
+    print_table(["Day", "Input", "Part", "Expected", "Actual"], &rows);
+
+    // Renderings don't fit in a table, so show them in full after it
+    for mismatch in mismatches {
+        let renderings = [mismatch.expected.as_ref(), mismatch.actual.as_ref().ok()];
+        if !renderings
+            .iter()
+            .flatten()
+            .any(|answer| answer.is_rendering())
+        {
+            continue;
+        }
+
+        println!();
+        println!(
+            "Day {} {} part {}",
+            mismatch.day,
+            optional_cell(mismatch.file),
+            optional_cell(mismatch.part.map(|part| part.number()))
+        );
+        let [expected, actual] = renderings;
+        println!("Expected:\n{}", optional_cell(expected));
+        println!("Actual:\n{}", optional_cell(actual));
+    }
+}
+
+pub fn answer_cell(answer: &Answer) -> String {
+    if answer.is_rendering() {
+        let text = answer.to_string();
+        let first_line = text.lines().next().unwrap_or_default();
+        format!(
+            "{} (+{} lines)",
+            first_line,
+            text.lines().count().saturating_sub(1)
+        )
+    } else {
+        answer.to_string()
+    }
+}
+
+// Parse errors are shown in a single line, without the snippet of the input
+fn error_cell(error: &anyhow::Error) -> String {
+    match error.downcast_ref::<ParseError>() {
+        Some(error) => format!("{:#}", error),
+        None => format!("{:#}", error),
+    }
+}
+
+fn optional_cell(value: Option<impl ToString>) -> String {
+    value.map_or_else(|| "-".into(), |value| value.to_string())
+}
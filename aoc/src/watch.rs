@@ -0,0 +1,322 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use anyhow::Context;
+use aoc_common::{Answer, AnswerManifest, InputFile, Part};
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+
+use crate::days;
+use crate::table::print_table;
+use crate::verify::answer_cell;
+
+/// How long to wait for more changes before rebuilding, since saving a file often takes a few.
+const SETTLE_TIME: Duration = Duration::from_millis(200);
+
+pub struct WatchOptions {
+    /// Build and run in release mode
+    pub release: bool,
+}
+
+type Answers = HashMap<(InputFile, Part), Result<Answer, String>>;
+
+/**
+ * Rebuilds and re-runs a day every time its code, its inputs or its `answers.toml` change, and
+ * compares the answers with the previous run and with the ones known to be right.
+ *
+ * The runner can't reload its own code, so each run goes through `cargo run -p aoc`, which only
+ * has to recompile the day that changed. Build errors are shown as cargo prints them, and watching
+ * goes on until the process is interrupted.
+ */
+pub fn watch(day: u8, options: &WatchOptions) -> anyhow::Result<()> {
+    // Events come with absolute paths
+    let directory = fs::canonicalize(days::directory(day)).with_context(|| {
+        format!(
+            "Unable to find {}, create it with `aoc new {}`",
+            days::directory(day),
+            day
+        )
+    })?;
+
+    let (sender, changes) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    // Editors often save by replacing files, which would end a watch on the file itself, so this
+    // watches the whole crate and filters the events instead
+    watcher
+        .watch(&directory, RecursiveMode::Recursive)
+        .with_context(|| format!("Unable to watch {}", directory.display()))?;
+
+    let mut previous = None;
+
+    loop {
+        let manifest = AnswerManifest::from_directory(&directory).unwrap_or_default();
+
+        if let Some(answers) = build_and_run(day, &directory, options)? {
+            print_comparison(&answers, previous.as_ref(), &manifest);
+            previous = Some(answers);
+        }
+
+        println!("\nWatching {} for changes...", directory.display());
+        let changed = wait_for_change(&changes, &directory)?;
+        println!("\n{} changed", changed.display());
+    }
+}
+
+// Blocks until something relevant to the day changes, and then until the changes stop for a bit
+fn wait_for_change(
+    changes: &mpsc::Receiver<notify::Result<notify::Event>>,
+    directory: &Path,
+) -> anyhow::Result<PathBuf> {
+    let changed = loop {
+        let event = changes.recv().context("Stopped watching for changes")??;
+        if let Some(path) = relevant_path(&event, directory) {
+            break path;
+        }
+    };
+
+    while changes.recv_timeout(SETTLE_TIME).is_ok() {}
+
+    Ok(changed)
+}
+
+fn relevant_path(event: &notify::Event, directory: &Path) -> Option<PathBuf> {
+    if matches!(event.kind, EventKind::Access(_)) {
+        return None;
+    }
+
+    event
+        .paths
+        .iter()
+        .find(|path| is_watched(path, directory))
+        .cloned()
+}
+
+// Sources of the day, its inputs and its expected answers, but not e.g. editor backups
+fn is_watched(path: &Path, directory: &Path) -> bool {
+    let Ok(relative) = path.strip_prefix(directory) else {
+        return false;
+    };
+
+    match relative.to_str() {
+        Some("sample.txt" | "input.txt" | AnswerManifest::FILE_NAME) => true,
+        _ => relative.starts_with("src") && relative.extension().is_some_and(|ext| ext == "rs"),
+    }
+}
+
+// Answers for each input the day has, or None if it doesn't build
+fn build_and_run(
+    day: u8,
+    directory: &Path,
+    options: &WatchOptions,
+) -> anyhow::Result<Option<Answers>> {
+    let status = cargo("build", options)
+        .status()
+        .context("Unable to run cargo")?;
+
+    if !status.success() {
+        println!("Build failed");
+        return Ok(None);
+    }
+
+    let mut answers = Answers::new();
+
+    for file in InputFile::ALL {
+        let path = directory.join(file.file_name());
+        if !path.exists() {
+            continue;
+        }
+
+        let output = cargo("run", options)
+            .args(["--", "run", &day.to_string(), "--format", "json"])
+            .arg("--input")
+            .arg(&path)
+            .stderr(Stdio::piped())
+            .output()
+            .context("Unable to run cargo")?;
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        answers.extend(parse_answers(file, &stdout, &stderr));
+    }
+
+    Ok(Some(answers))
+}
+
+fn cargo(subcommand: &str, options: &WatchOptions) -> Command {
+    let mut command = Command::new(std::env::var("CARGO").unwrap_or_else(|_| "cargo".into()));
+    command.args([subcommand, "--quiet", "--color", "always", "-p", "aoc"]);
+    if options.release {
+        command.arg("--release");
+    }
+    command
+}
+
+#[derive(Deserialize)]
+struct Solved {
+    part: u8,
+    answer: Option<Answer>,
+}
+
+// The runner prints a JSON line per part until one of them fails, and the error to stderr then.
+// Parts that aren't solved yet have a null answer.
+fn parse_answers(
+    file: InputFile,
+    stdout: &str,
+    stderr: &str,
+) -> impl Iterator<Item = ((InputFile, Part), Result<Answer, String>)> {
+    let mut answers: HashMap<Part, Result<Answer, String>> = stdout
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Solved>(line).ok())
+        .filter_map(|solved| {
+            let answer = solved.answer.ok_or_else(|| "not solved yet".to_string());
+            Some((Part::try_from(solved.part).ok()?, answer))
+        })
+        .collect();
+
+    let error = stderr
+        .lines()
+        .find_map(|line| line.strip_prefix("Error: "))
+        .unwrap_or("no answer")
+        .to_string();
+
+    for part in Part::ALL {
+        answers.entry(part).or_insert_with(|| Err(error.clone()));
+    }
+
+    answers
+        .into_iter()
+        .map(move |(part, answer)| ((file, part), answer))
+}
+
+fn print_comparison(answers: &Answers, previous: Option<&Answers>, manifest: &AnswerManifest) {
+    let mut keys: Vec<&(InputFile, Part)> = answers.keys().collect();
+    keys.sort();
+
+    let rows: Vec<[String; 5]> = keys
+        .into_iter()
+        .map(|&(file, part)| {
+            let answer = &answers[&(file, part)];
+            let previous = previous.map(|previous| previous.get(&(file, part)));
+            let expected = manifest.expected(file).get(part);
+
+            [
+                file.to_string(),
+                part.number().to_string(),
+                result_cell(answer),
+                compare_with_previous(answer, previous),
+                compare_with_expected(answer, expected),
+            ]
+        })
+        .collect();
+
+    print_table(
+        ["Input", "Part", "Answer", "Previous run", "Expected"],
+        &rows,
+    );
+}
+
+fn result_cell(answer: &Result<Answer, String>) -> String {
+    match answer {
+        Ok(answer) => answer_cell(answer),
+        Err(error) => format!("error: {}", error),
+    }
+}
+
+// None for the first run, and Some(None) for a part that didn't exist in the previous one
+fn compare_with_previous(
+    answer: &Result<Answer, String>,
+    previous: Option<Option<&Result<Answer, String>>>,
+) -> String {
+    match previous {
+        None => "-".into(),
+        Some(Some(previous)) if previous == answer => "same".into(),
+        Some(Some(previous)) => format!("was {}", result_cell(previous)),
+        Some(None) => "new".into(),
+    }
+}
+
+fn compare_with_expected(answer: &Result<Answer, String>, expected: Option<&Answer>) -> String {
+    match expected {
+        None => "unknown".into(),
+        Some(expected) if answer.as_ref() == Ok(expected) => "right".into(),
+        Some(expected) => format!("WRONG, should be {}", answer_cell(expected)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_answers_until_a_part_fails() {
+        let stdout = r#"{"day":7,"part":1,"answer":95437,"elapsed_ns":1500}"#;
+        let stderr = "Error: Division by zero\n";
+
+        let answers: Answers = parse_answers(InputFile::Sample, stdout, stderr).collect();
+
+        assert_eq!(
+            answers[&(InputFile::Sample, Part::One)],
+            Ok(Answer::from(95437_i64))
+        );
+        assert_eq!(
+            answers[&(InputFile::Sample, Part::Two)],
+            Err("Division by zero".into())
+        );
+    }
+
+    #[test]
+    fn reads_unsolved_parts() {
+        let stdout = r#"{"day":17,"part":2,"answer":null,"elapsed_ns":1500}"#;
+
+        let answers: Answers = parse_answers(InputFile::Real, stdout, "").collect();
+
+        assert_eq!(
+            answers[&(InputFile::Real, Part::Two)],
+            Err("not solved yet".into())
+        );
+    }
+
+    #[test]
+    fn compares_with_previous_run() {
+        let answer = Ok(Answer::from(12_i64));
+
+        assert_eq!(compare_with_previous(&answer, None), "-");
+        assert_eq!(compare_with_previous(&answer, Some(Some(&answer))), "same");
+        assert_eq!(
+            compare_with_previous(&answer, Some(Some(&Ok(Answer::from(10_i64))))),
+            "was 10"
+        );
+        assert_eq!(compare_with_previous(&answer, Some(None)), "new");
+    }
+
+    #[test]
+    fn compares_with_expected_answer() {
+        let answer = Ok(Answer::from(12_i64));
+
+        assert_eq!(compare_with_expected(&answer, None), "unknown");
+        assert_eq!(
+            compare_with_expected(&answer, Some(&Answer::from(12_i64))),
+            "right"
+        );
+        assert_eq!(
+            compare_with_expected(&Err("oops".into()), Some(&Answer::from(12_i64))),
+            "WRONG, should be 12"
+        );
+    }
+
+    #[test]
+    fn only_watches_sources_and_inputs() {
+        let directory = Path::new("day07");
+
+        assert!(is_watched(Path::new("day07/src/lib.rs"), directory));
+        assert!(is_watched(Path::new("day07/sample.txt"), directory));
+        assert!(is_watched(Path::new("day07/answers.toml"), directory));
+        assert!(!is_watched(Path::new("day07/src/.lib.rs.swp"), directory));
+        assert!(!is_watched(Path::new("day07/notes.txt"), directory));
+        assert!(!is_watched(Path::new("day08/src/lib.rs"), directory));
+    }
+}
@@ -0,0 +1,52 @@
+use anyhow::Context;
+use aoc_common::{parse_number, Answer, Input, Solution};
+
+/**
+ * --- Day 1: Calorie Counting ---
+ *
+ * The input is a list of lists, each element is an elf and each element of the sublist is the
+ * number of calories of the different snacks it carries.
+ * Both parts of the problem care about the total calories carried by each elf, so this program
+ * represents an elf as just a `usize` which is the total number of calories carried.
+ * Not a whole lot going on - split lines, sum, sort, take the largest (or largest 3) to solve the
+ * challenge.
+ */
+pub struct Day01 {
+    // Sorted from most to least calories
+    elves: Vec<usize>,
+}
+
+impl Solution for Day01 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let mut elves: Vec<usize> = input
+            .paragraphs()
+            .map(|calories| {
+                calories
+                    .iter()
+                    .map(|&(number, line)| {
+                        parse_number::<usize>(line, line)
+                            .map_err(|error| input.locate(error, number))
+                    })
+                    .sum()
+            })
+            .collect::<Result<_, _>>()?;
+
+        elves.sort_unstable();
+        elves.reverse();
+
+        Ok(Day01 { elves })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1 = self.elves.first().context("Unable to find max calories")?;
+        Ok((*part_1).into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let part_2: usize = self.elves.iter().take(3).sum();
+        Ok(part_2.into())
+    }
+}
+
+aoc_common::sample_tests!(Day01);
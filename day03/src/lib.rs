@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use aoc_common::{Answer, Input, ParseError, Solution};
+
+/**
+ * --- Day 3: Rucksack Reorganization ---
+ *
+ * The input is a list of rucksacks where each character is an item contained in it. Each rucksack
+ * has two compartments, the first half of the items are in one and the second half in the other.
+ * Each item is represented by a single character and has a "priority" based on which character it
+ * is.
+ * Part 1 of the challenge asks to find, for each rucksack, the only item that is in both
+ * compartments and add up the priorities of all of them. Part 2 asks to find, for each group of 3
+ * consecutive rucksacks, the only item that is in all three of them and add up the priorities of
+ * the shared item of each group.
+ * The solution uses two sets to represents each rucksack, one for each compartment. This makes it
+ * easy to do an intersection of both compartments to find the element required in part 1, and
+ * to make an union of them to find all the items in a single rucksack and then do an intersection
+ * with the other rucksacks for part 2.
+ * The fact that there's only one item matching the condition on either part is not checked, and
+ * the code would work even if there were multiple.
+ */
+pub struct Day03 {
+    rucksacks: Vec<Rucksack>,
+}
+
+impl Solution for Day03 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let rucksacks: Vec<Rucksack> = input.parse_lines()?;
+
+        // Part 2 splits the rucksacks in groups of 3, and a partial group can't have a badge
+        if !rucksacks.len().is_multiple_of(3) {
+            let (number, line) = input.numbered_lines().last().unwrap_or_default();
+            let message = format!(
+                "Expected groups of 3 rucksacks, but there are {}",
+                rucksacks.len()
+            );
+            return Err(input.locate(ParseError::new(message, line), number).into());
+        }
+
+        Ok(Day03 { rucksacks })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1: usize = self
+            .rucksacks
+            .iter()
+            .flat_map(|rucksack| rucksack.items_in_common())
+            .map(|item| item.priority())
+            .sum();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let part_2: usize = self
+            .rucksacks
+            .chunks_exact(3)
+            .map(|group| {
+                let [a, b, c] = group else {
+                    unreachable!("Parsing makes sure rucksacks come in groups of 3")
+                };
+
+                a.items()
+                    .intersection(&b.items())
+                    .copied()
+                    .collect::<HashSet<&Item>>()
+                    .intersection(&c.items())
+                    .map(|item| item.priority())
+                    .sum::<usize>()
+            })
+            .sum();
+        Ok(part_2.into())
+    }
+}
+
+struct Rucksack {
+    first_compartment: HashSet<Item>,
+    second_compartment: HashSet<Item>,
+}
+
+impl Rucksack {
+    // Returns *all* items in the Rucksack
+    fn items(&self) -> HashSet<&Item> {
+        self.first_compartment
+            .union(&self.second_compartment)
+            .collect()
+    }
+
+    // Returns only the items that exist in *both* compartments of the Rucksack
+    fn items_in_common(&self) -> HashSet<&Item> {
+        self.first_compartment
+            .intersection(&self.second_compartment)
+            .collect()
+    }
+}
+
+impl FromStr for Rucksack {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let items = s
+            .chars()
+            .enumerate()
+            .map(|(column, char)| {
+                Item::try_from(char).map_err(|_| {
+                    ParseError::with_columns(
+                        format!("Invalid item {}", char),
+                        s,
+                        column..column + 1,
+                    )
+                })
+            })
+            .collect::<Result<Vec<Item>, _>>()?;
+        let (first, second) = items.split_at(items.len() / 2);
+
+        Ok(Rucksack {
+            first_compartment: first.iter().copied().collect(),
+            second_compartment: second.iter().copied().collect(),
+        })
+    }
+}
+
+#[derive(PartialEq, Eq, Hash, Clone, Copy)]
+struct Item {
+    letter: char,
+}
+
+impl Item {
+    fn priority(&self) -> usize {
+        // Since the letters have values that increase from A to Z, we start with each letter's
+        // ASCII value and offset it to simplify the calculation.
+        match self.letter {
+            'a'..='z' => self.letter as usize - 96,
+            'A'..='Z' => self.letter as usize - 38,
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl TryFrom<char> for Item {
+    type Error = char;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'a'..='z' | 'A'..='Z' => Ok(Item { letter: value }),
+            c => Err(c),
+        }
+    }
+}
+
+aoc_common::sample_tests!(Day03);
@@ -0,0 +1,91 @@
+use std::str::FromStr;
+
+use aoc_common::{parse_number, Answer, Input, ParseError, ParseResult, Solution};
+
+/**
+ * --- Day 4: Camp Cleanup ---
+ *
+ * The challenge provides a list of range pairs. Part 1 asks whether one of the ranges in the pair
+ * entirely contains another. Part 2 asks whether they overlap.
+ * This program pretty much implements that literally, most of the code is parsing. A custom struct
+ * is used instead of Rust's `RangeInclusive` for the small convenience of implementing `FromStr` on
+ * it. Plus, Rust's ranges are iterators but here we only care about start and end values.
+ */
+pub struct Day04 {
+    ranges: Vec<(AssignmentRange, AssignmentRange)>,
+}
+
+impl Solution for Day04 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let ranges: Vec<(AssignmentRange, AssignmentRange)> = input.parse_lines_with(parse_line)?;
+
+        Ok(Day04 { ranges })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1 = self
+            .ranges
+            .iter()
+            .filter(|(first_range, second_range)| {
+                first_range.contains(second_range) || second_range.contains(first_range)
+            })
+            .count();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let part_2 = self
+            .ranges
+            .iter()
+            .filter(|(first_range, second_range)| first_range.overlaps(second_range))
+            .count();
+        Ok(part_2.into())
+    }
+}
+
+struct AssignmentRange {
+    from: usize,
+    to: usize,
+}
+
+impl AssignmentRange {
+    fn contains(&self, other: &AssignmentRange) -> bool {
+        self.from <= other.from && self.to >= other.to
+    }
+
+    fn overlaps(&self, other: &AssignmentRange) -> bool {
+        (self.from <= other.from && self.to >= other.from)
+            || (other.from <= self.from && other.to >= self.from)
+    }
+}
+
+impl FromStr for AssignmentRange {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (from, to) = s
+            .split_once('-')
+            .ok_or_else(|| ParseError::new("Expected a range like 2-4", s))?;
+
+        Ok(AssignmentRange {
+            from: parse_number(s, from)?,
+            to: parse_number(s, to)?,
+        })
+    }
+}
+
+fn parse_line(line: &str) -> ParseResult<(AssignmentRange, AssignmentRange)> {
+    let (first, second) = line
+        .split_once(',')
+        .ok_or_else(|| ParseError::new("Expected two ranges separated by a comma", line))?;
+    let parse = |range: &str| {
+        range
+            .parse()
+            .map_err(|error: ParseError| error.within(line, range))
+    };
+
+    Ok((parse(first)?, parse(second)?))
+}
+
+aoc_common::sample_tests!(Day04);
@@ -0,0 +1,278 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use aoc_common::{parse_number, Answer, Input, ParseError, ParseResult, Solution};
+use tracing::debug;
+
+/**
+ * --- Day 5: Supply Stacks ---
+ *
+ * The challenge provides several stacks, each of them with crates (where each
+ * crate is identified by a single letter). Then it provides a series of
+ * instructions that do operations on crates, moving them across stacks.
+ *
+ * The program parses initial stacks and instructions separately. Then creates
+ * two different cranes with different `run` implementations, gives each a copy
+ * of the initial positions of the stacks and runs the instructions. The solution
+ * is formed by taking the top element of each stack.
+ */
+pub struct Day05 {
+    starting_stacks: Vec<Stack>,
+    instructions: Vec<Instruction>,
+}
+
+impl Solution for Day05 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let mut paragraphs = input.paragraphs();
+
+        let starting_position = paragraphs.next().unwrap_or_default();
+        let starting_stacks = parse_stacks(input, starting_position)?;
+
+        let instructions: Vec<Instruction> = paragraphs
+            .flatten()
+            .map(|(number, line)| {
+                parse_instruction(line, number, starting_stacks.len())
+                    .map_err(|error| input.locate(error, number))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day05 {
+            starting_stacks,
+            instructions,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let mut crane = CrateMover9000 {
+            stacks: self.starting_stacks.clone(),
+        };
+        crane.run(&self.instructions)?;
+        Ok(crane.top_krates().into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let mut crane = CrateMover9001 {
+            stacks: self.starting_stacks.clone(),
+        };
+        crane.run(&self.instructions)?;
+        Ok(crane.top_krates().into())
+    }
+}
+
+fn parse_stacks(input: &Input, mut lines: Vec<(usize, &str)>) -> ParseResult<Vec<Stack>> {
+    let mut stacks = vec![];
+
+    // Reversing the lines simplifies parsing. That way the first line will have the
+    // ids of the stacks, and we go from bottom to top for each of them (which is the
+    // order that is more convenient to "push" into each stack).
+    lines.reverse();
+
+    let (_, ids) = lines
+        .first()
+        .ok_or_else(|| input.locate(ParseError::new("Missing starting stacks", ""), 1))?;
+
+    for _ in ids.split_whitespace() {
+        // We could parse this, but we know that it will be the numbers 1, 2, 3...
+        stacks.push(Stack::new());
+    }
+
+    for &(number, line) in lines.iter().skip(1) {
+        let chars: Vec<char> = line.chars().collect();
+        let error = |message: String, column: usize| {
+            input.locate(
+                ParseError::with_columns(message, line, column..column + 1),
+                number,
+            )
+        };
+
+        // We know the lines will have the structure [X] [Y] [Z] with different
+        // amounts of whitespace between them, and that whitespace will be
+        // significant in order to know which stack each crate belongs to.
+        // So each stack takes 4 characters, with its crate (or whitespace when
+        // it has no crate that high) in the second one.
+        for (index, stack) in stacks.iter_mut().enumerate() {
+            let column = index * 4 + 1;
+
+            match chars.get(column) {
+                Some(' ') => {}
+                Some(&krate) if krate.is_ascii_alphabetic() => stack.push(krate),
+                Some(&other) => {
+                    return Err(error(format!("Invalid crate {}", other), column));
+                }
+                // None means that we reached end of line, no need to keep looking
+                // for crates.
+                None => break,
+            }
+        }
+
+        if let Some(column) = (stacks.len() * 4..chars.len()).find(|&i| chars[i] != ' ') {
+            return Err(error(
+                format!("There are only {} stacks", stacks.len()),
+                column,
+            ));
+        }
+    }
+
+    Ok(stacks)
+}
+
+fn parse_instruction(line: &str, number: usize, stack_count: usize) -> ParseResult<Instruction> {
+    let instruction = Instruction {
+        line: number,
+        ..line.parse()?
+    };
+
+    // Parsing succeeded, so the stacks are the 4th and 6th words
+    let words: Vec<&str> = line.split_whitespace().collect();
+    for (stack, word) in [(instruction.from, words[3]), (instruction.to, words[5])] {
+        if stack >= stack_count {
+            return Err(ParseError::at(
+                format!("There are only {} stacks", stack_count),
+                line,
+                word,
+            ));
+        }
+    }
+
+    Ok(instruction)
+}
+
+trait Crane {
+    fn run(&mut self, instructions: &[Instruction]) -> anyhow::Result<()>;
+
+    fn stacks(&self) -> &[Stack];
+
+    fn top_krates(&self) -> String {
+        self.stacks()
+            .iter()
+            .filter_map(|stack| stack.peek())
+            .collect()
+    }
+}
+
+struct CrateMover9000 {
+    stacks: Vec<Stack>,
+}
+
+impl Crane for CrateMover9000 {
+    fn stacks(&self) -> &[Stack] {
+        &self.stacks
+    }
+
+    fn run(&mut self, instructions: &[Instruction]) -> anyhow::Result<()> {
+        for instruction in instructions {
+            debug!(?instruction, "Moving crates one at a time");
+
+            // Moving crates one at a time leaves them in reverse order
+            let krates = instruction.take_crates(&mut self.stacks)?;
+            self.stacks[instruction.to].extend(krates.into_iter().rev());
+        }
+
+        Ok(())
+    }
+}
+
+struct CrateMover9001 {
+    stacks: Vec<Stack>,
+}
+
+impl Crane for CrateMover9001 {
+    fn stacks(&self) -> &[Stack] {
+        &self.stacks
+    }
+
+    fn run(&mut self, instructions: &[Instruction]) -> anyhow::Result<()> {
+        for instruction in instructions {
+            debug!(?instruction, "Moving crates all at once");
+            let next_group = instruction.take_crates(&mut self.stacks)?;
+            self.stacks[instruction.to].extend(next_group);
+        }
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Stack(Vec<char>);
+
+impl Stack {
+    fn new() -> Stack {
+        Stack(vec![])
+    }
+
+    fn push(&mut self, krate: char) {
+        self.0.push(krate)
+    }
+
+    fn peek(&self) -> Option<&char> {
+        self.0.last()
+    }
+
+    fn take_from_top(&mut self, amount: usize) -> anyhow::Result<Vec<char>> {
+        let stack_length = self.0.len();
+        let Some(start) = stack_length.checked_sub(amount) else {
+            anyhow::bail!("It has {} crates, not {}", stack_length, amount);
+        };
+
+        Ok(self.0.split_off(start))
+    }
+
+    fn extend(&mut self, other: impl IntoIterator<Item = char>) {
+        self.0.extend(other);
+    }
+}
+
+#[derive(Debug)]
+struct Instruction {
+    movement: usize,
+    from: usize,
+    to: usize,
+    /// Where the instruction is in the input, to point at it when it can't be followed
+    line: usize,
+}
+
+impl Instruction {
+    // The crates the instruction moves, in the order they were in the stack
+    fn take_crates(&self, stacks: &mut [Stack]) -> anyhow::Result<Vec<char>> {
+        stacks[self.from]
+            .take_from_top(self.movement)
+            .with_context(|| {
+                format!(
+                    "Unable to move crates from stack {} on line {}",
+                    self.from + 1,
+                    self.line
+                )
+            })
+    }
+}
+
+impl FromStr for Instruction {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let ["move", movement, "from", from, "to", to] = words[..] else {
+            return Err(ParseError::new(
+                "Expected an instruction like move 1 from 2 to 3",
+                s,
+            ));
+        };
+
+        // Instructions are 1-indexed but that's inconvenient so we parse them to
+        // 0-indexed early.
+        let stack = |word: &str| match parse_number::<usize>(s, word)? {
+            0 => Err(ParseError::at("Stacks are numbered from 1", s, word)),
+            id => Ok(id - 1),
+        };
+
+        Ok(Instruction {
+            movement: parse_number(s, movement)?,
+            from: stack(from)?,
+            to: stack(to)?,
+            // Filled in by `parse_instruction`, which knows where the line is
+            line: 0,
+        })
+    }
+}
+
+aoc_common::sample_tests!(Day05);
@@ -0,0 +1,61 @@
+use std::collections::HashSet;
+
+use anyhow::Context;
+use aoc_common::{Answer, Input, Solution};
+
+/**
+ * --- Day 6: Tuning Trouble ---
+ *
+ * The challenge provides a string that represent a signal. It's made out a bunch of characters.
+ * Then it asks to find "markers", which are made of substrings of a given length with no repeated
+ * characters.
+ *
+ * The program creates windows over the provided string and checks the number of unique elements on
+ * each window by creating a set out of it. If it matches the length we're looking for, the marker
+ * has been found.
+ */
+pub struct Day06 {
+    signal: Vec<char>,
+}
+
+impl Solution for Day06 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let signal: Vec<char> = input
+            .lines()
+            .next()
+            .context("Unable to find first line")?
+            .chars()
+            .collect();
+
+        Ok(Day06 { signal })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1 =
+            find_marker(&self.signal, 4).context("Could not find start-of-packet marker")?;
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let part_2 =
+            find_marker(&self.signal, 14).context("Could not find start-of-message marker")?;
+        Ok(part_2.into())
+    }
+}
+
+fn find_marker(signal: &[char], length: usize) -> Option<usize> {
+    signal
+        .windows(length)
+        .map(|window| HashSet::<&char>::from_iter(window.iter()).len())
+        .enumerate()
+        .find_map(|(i, unique_char_count)| {
+            if unique_char_count == length {
+                Some(i + length)
+            } else {
+                None
+            }
+        })
+}
+
+aoc_common::sample_tests!(Day06);
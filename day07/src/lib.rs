@@ -0,0 +1,228 @@
+use std::str::FromStr;
+
+use anyhow::Context;
+use aoc_common::{parse_number, Answer, Input, ParseError, Solution};
+use petgraph::prelude::*;
+
+const CAPACITY: usize = 70_000_000;
+const UPDATE_SIZE: usize = 30_000_000;
+
+/**
+ * --- Day 7: No Space Left On Device ---
+ *
+ * The challenge provides input/output like a terminal running some `cd` and `ls` commands. It asks
+ * to find different directory sizes following some rules.
+ *
+ * This program goes line by line inferring the filesystem from that. Lines can be either input
+ * (commands) or output (node descriptions, where node is either a file or a directory). It
+ * represents the filesystem with a graph structure that is used conceptually as a tree. Most of the
+ * code is about interpreting the lines provided - calculating sizes is relatively straightforward
+ * after that.
+ *
+ * Some things of the implementation are not enforced by the type-system and therefore rely on the
+ * code not having bugs. One is that there's nothing preventing files from containing other nodes
+ * (which would be a `Node::File` with outgoing edges). Another is that a given node can technically
+ * have many parents (multiple incoming edges). The code assumes this isn't the case, but the data
+ * structure does not prevent it.
+ */
+pub struct Day07 {
+    filesystem: Filesystem,
+}
+
+impl Solution for Day07 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let lines: Vec<Line> = input.parse_lines()?;
+
+        let mut filesystem = Filesystem::new(CAPACITY);
+        filesystem.infer_from(lines.into_iter());
+
+        Ok(Day07 { filesystem })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1: usize = self
+            .filesystem
+            .directory_sizes()
+            .filter(|size| *size <= 100_000)
+            .sum();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let extra_space_needed = UPDATE_SIZE - self.filesystem.free_space();
+
+        let part_2 = self
+            .filesystem
+            .directory_sizes()
+            .filter(|size| *size >= extra_space_needed)
+            .min()
+            .context("Could not find directory with minimum required space")?;
+        Ok(part_2.into())
+    }
+}
+
+struct Filesystem {
+    storage: DiGraph<Node, ()>,
+    capacity: usize,
+}
+
+impl Filesystem {
+    fn new(capacity: usize) -> Self {
+        let mut storage = DiGraph::new();
+        storage.add_node(Node::Directory("/".into()));
+        Filesystem { storage, capacity }
+    }
+
+    fn root(&self) -> NodeIndex {
+        self.storage
+            .externals(Incoming)
+            .next()
+            .expect("Could not find root directory")
+    }
+
+    fn free_space(&self) -> usize {
+        self.capacity - self.node_size(self.root())
+    }
+
+    fn directory_sizes(&self) -> impl Iterator<Item = usize> + '_ {
+        self.storage
+            .node_indices()
+            .filter_map(|node_index| match self.storage[node_index] {
+                Node::Directory(_) => Some(self.node_size(node_index)),
+                Node::File(_, _) => None,
+            })
+    }
+
+    fn node_size(&self, node_index: NodeIndex) -> usize {
+        match self.storage[node_index] {
+            Node::File(_, size) => size,
+            Node::Directory(_) => self
+                .storage
+                .edges_directed(node_index, Outgoing)
+                .map(|edge| self.node_size(edge.target()))
+                .sum(),
+        }
+    }
+
+    fn infer_from(&mut self, lines: impl Iterator<Item = Line>) {
+        let mut current_directory = self.root();
+
+        for line in lines {
+            match line {
+                Line::Input(command) => match command {
+                    Command::Cd(destination) => match destination.as_str() {
+                        "/" => current_directory = self.root(),
+                        ".." => {
+                            let parent = self
+                                .storage
+                                .edges_directed(current_directory, Incoming)
+                                .next()
+                                .expect("Tried to find parent of root directory")
+                                .source();
+                            current_directory = parent;
+                        }
+                        name => {
+                            let next_node_edge = self
+                                .storage
+                                .edges_directed(current_directory, Outgoing)
+                                .find(|edge| {
+                                    self.storage[edge.target()] == Node::Directory(name.into())
+                                });
+
+                            current_directory = if let Some(edge) = next_node_edge {
+                                edge.target()
+                            } else {
+                                let new_directory =
+                                    self.storage.add_node(Node::Directory(name.into()));
+                                self.storage.add_edge(current_directory, new_directory, ());
+                                new_directory
+                            };
+                        }
+                    },
+                    Command::Ls => {}
+                },
+                Line::Output(node_description) => {
+                    let new_node = match node_description {
+                        NodeDescription::File(name, size) => {
+                            self.storage.add_node(Node::File(name, size))
+                        }
+                        NodeDescription::Directory(name) => {
+                            self.storage.add_node(Node::Directory(name))
+                        }
+                    };
+
+                    self.storage.add_edge(current_directory, new_node, ());
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq)]
+enum Node {
+    Directory(String),
+    File(String, usize),
+}
+
+enum Line {
+    Input(Command),
+    Output(NodeDescription),
+}
+
+impl FromStr for Line {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.starts_with("$ ") {
+            Ok(Line::Input(s.parse()?))
+        } else {
+            Ok(Line::Output(s.parse()?))
+        }
+    }
+}
+
+enum Command {
+    Cd(String),
+    Ls,
+}
+
+impl FromStr for Command {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(destination) = s.strip_prefix("$ cd ") {
+            Ok(Command::Cd(destination.into()))
+        } else if s == "$ ls" {
+            Ok(Command::Ls)
+        } else {
+            Err(ParseError::at(
+                "Invalid command",
+                s,
+                s.strip_prefix("$ ").unwrap_or(s),
+            ))
+        }
+    }
+}
+
+enum NodeDescription {
+    Directory(String),
+    File(String, usize),
+}
+
+impl FromStr for NodeDescription {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(name) = s.strip_prefix("dir ") {
+            Ok(NodeDescription::Directory(name.into()))
+        } else {
+            let (size, name) = s
+                .split_once(' ')
+                .ok_or_else(|| ParseError::new("Expected a file size and name", s))?;
+            Ok(NodeDescription::File(name.into(), parse_number(s, size)?))
+        }
+    }
+}
+
+aoc_common::sample_tests!(Day07);
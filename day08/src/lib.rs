@@ -0,0 +1,153 @@
+use std::collections::HashSet;
+
+use aoc_common::{Answer, Direction, Frame, FrameSink, Grid, Input, Part, Solution};
+
+pub struct Day08 {
+    forest: Forest,
+}
+
+impl Solution for Day08 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let forest = Forest {
+            trees: Grid::parse(input, |char| {
+                char.to_digit(10)
+                    .map(|digit| digit as usize)
+                    .ok_or_else(|| format!("Invalid tree height {}", char))
+            })?,
+        };
+
+        Ok(Day08 { forest })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1 = self.forest.visible_count();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let part_2 = self.forest.highest_scenic_score();
+        Ok(part_2.into())
+    }
+
+    // There's nothing to simulate, so each part gets a single picture of what it found
+    fn animate(&self, part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let frame = match part {
+            Part::One => self.forest.visibility_map(),
+            Part::Two => self.forest.scenic_heatmap(),
+        };
+        let _ = sink.show(frame);
+
+        Ok(())
+    }
+}
+
+struct Forest {
+    trees: Grid<usize>,
+}
+
+impl Forest {
+    fn visible_count(&self) -> usize {
+        self.visible_trees().len()
+    }
+
+    fn visible_trees(&self) -> HashSet<(usize, usize)> {
+        let mut visible = HashSet::<(usize, usize)>::new();
+
+        for tree_line in self.tree_lines() {
+            let mut max = None;
+
+            visible.extend(tree_line.into_iter().filter(move |&position| match max {
+                Some(number) if number < self.trees[position] => {
+                    max = Some(self.trees[position]);
+                    true
+                }
+                None => {
+                    max = Some(self.trees[position]);
+                    true
+                }
+                _ => false,
+            }));
+        }
+
+        visible
+    }
+
+    fn tree_lines(&self) -> Vec<Vec<(usize, usize)>> {
+        let rows = 0..self.trees.height();
+        let columns = 0..self.trees.width();
+
+        let mut lines: Vec<Vec<(usize, usize)>> = vec![];
+        lines.extend(rows.clone().map(|y| self.trees.row(y).collect()));
+        lines.extend(rows.map(|y| self.trees.row(y).rev().collect()));
+        lines.extend(columns.clone().map(|x| self.trees.column(x).collect()));
+        lines.extend(columns.map(|x| self.trees.column(x).rev().collect()));
+
+        lines
+    }
+
+    fn highest_scenic_score(&self) -> usize {
+        self.trees
+            .positions()
+            .map(|position| self.scenic_score(position))
+            .max()
+            .unwrap_or_default()
+    }
+
+    // Visible trees with #, hidden ones with .
+    fn visibility_map(&self) -> Frame {
+        let visible = self.visible_trees();
+        let cells = Grid::from_fn(self.trees.width(), self.trees.height(), |position| {
+            if visible.contains(&position) {
+                '#'
+            } else {
+                '.'
+            }
+        });
+
+        Frame::new(format!("{} visible trees", visible.len()), cells)
+    }
+
+    // Scenic score of each tree from 0 to 9, relative to the highest one. Scores are spread over a
+    // square root scale, since most trees have a low score and a few have a very high one.
+    fn scenic_heatmap(&self) -> Frame {
+        let scores = Grid::from_fn(self.trees.width(), self.trees.height(), |position| {
+            self.scenic_score(position)
+        });
+        let highest = scores
+            .iter()
+            .map(|(_, &score)| score)
+            .max()
+            .unwrap_or_default();
+
+        let cells = scores.map(|&score| {
+            let level = (9.0 * (score as f64 / highest.max(1) as f64).sqrt()).round();
+            char::from_digit(level as u32, 10).unwrap_or('9')
+        });
+
+        Frame::new(format!("Highest scenic score {}", highest), cells)
+    }
+
+    fn scenic_score(&self, position: (usize, usize)) -> usize {
+        let height = self.trees[position];
+
+        Direction::ALL
+            .into_iter()
+            .map(|direction| {
+                let mut visible = 0;
+
+                for other in self.trees.ray(position, direction.delta()) {
+                    visible += 1;
+
+                    if self.trees[other] >= height {
+                        break;
+                    }
+                }
+
+                visible
+            })
+            .product()
+    }
+}
+
+aoc_common::sample_tests!(Day08);
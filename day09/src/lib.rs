@@ -0,0 +1,174 @@
+use std::collections::HashSet;
+use std::iter;
+use std::ops::ControlFlow;
+use std::str::FromStr;
+
+use aoc_common::{
+    parse_number, Answer, Direction, Frame, FrameSink, Grid, Input, ParseError, Part, Point,
+    Solution,
+};
+use tracing::debug;
+
+pub struct Day09 {
+    movements: Vec<Move>,
+}
+
+impl Solution for Day09 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let movements: Vec<Move> = input.parse_lines()?;
+
+        Ok(Day09 { movements })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let mut rope = Rope::new(2);
+        rope.follow_movements(&self.movements, |_| ControlFlow::Continue(()));
+
+        let part_1 = rope.visited_spots();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let mut rope = Rope::new(10);
+        rope.follow_movements(&self.movements, |_| ControlFlow::Continue(()));
+
+        let part_2 = rope.visited_spots();
+        Ok(part_2.into())
+    }
+
+    fn animate(&self, part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let length = match part {
+            Part::One => 2,
+            Part::Two => 10,
+        };
+
+        let mut rope = Rope::new(length);
+        rope.follow_movements(&self.movements, |rope| sink.show(rope.frame()));
+
+        Ok(())
+    }
+}
+
+struct Rope {
+    segments: Vec<Point>,
+    visited: HashSet<Point>,
+}
+
+impl Rope {
+    fn new(length: usize) -> Self {
+        Rope {
+            segments: iter::repeat_n(Point::ORIGIN, length).collect(),
+            visited: HashSet::from([Point::ORIGIN]),
+        }
+    }
+
+    // `observe` is called after every step of the head
+    fn follow_movements(
+        &mut self,
+        movements: &[Move],
+        mut observe: impl FnMut(&Rope) -> ControlFlow<()>,
+    ) {
+        for movement in movements {
+            debug!(?movement, head = ?self.segments[0], "Moving the head");
+
+            for _ in 0..movement.distance {
+                self.segments[0] += movement.direction.delta();
+
+                for i in 1..self.segments.len() {
+                    let previous = self.segments[i - 1];
+                    let segment = &mut self.segments[i];
+
+                    // Segments that stop touching the previous one take a step towards it,
+                    // diagonally if they aren't in the same row or column
+                    if previous.chebyshev(*segment) > 1 {
+                        *segment += (previous - *segment).signum();
+                    }
+                }
+
+                self.visited
+                    .insert(*self.segments.last().expect("Empty rope"));
+
+                if observe(self).is_break() {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn visited_spots(&self) -> usize {
+        self.visited.len()
+    }
+
+    // Everything the tail visited and the whole rope, with the same symbols as the puzzle: H for
+    // the head and T for the tail when there are only two knots, numbers for the ones after the
+    // head otherwise, s for the start and # for visited positions
+    fn frame(&self) -> Frame {
+        let points = || self.visited.iter().chain(&self.segments);
+        let left = points().map(|point| point.x).min().unwrap_or_default();
+        let top = points().map(|point| point.y).min().unwrap_or_default();
+        let right = points().map(|point| point.x).max().unwrap_or_default();
+        let bottom = points().map(|point| point.y).max().unwrap_or_default();
+
+        let mut cells = Grid::new(
+            (right - left + 1) as usize,
+            (bottom - top + 1) as usize,
+            '.',
+        );
+        let cell = |point: Point| ((point.x - left) as usize, (point.y - top) as usize);
+
+        for &point in &self.visited {
+            cells[cell(point)] = '#';
+        }
+        cells[cell(Point::ORIGIN)] = 's';
+
+        for (index, &segment) in self.segments.iter().enumerate().rev() {
+            cells[cell(segment)] = match index {
+                0 => 'H',
+                1 if self.segments.len() == 2 => 'T',
+                index => char::from_digit(index as u32, 36).unwrap_or('*'),
+            };
+        }
+
+        let caption = format!("The tail visited {} positions", self.visited.len());
+        Frame::new(caption, cells)
+    }
+}
+
+#[derive(Debug)]
+struct Move {
+    direction: Direction,
+    distance: usize,
+}
+
+impl FromStr for Move {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (direction, distance) = s
+            .split_once(' ')
+            .ok_or_else(|| ParseError::new("Expected a direction and a distance", s))?;
+        let distance = parse_number(s, distance)?;
+
+        let direction = match direction {
+            "R" => Direction::Right,
+            "L" => Direction::Left,
+            "U" => Direction::Up,
+            "D" => Direction::Down,
+            other => {
+                return Err(ParseError::at(
+                    format!("Invalid direction {}", other),
+                    s,
+                    other,
+                ))
+            }
+        };
+
+        Ok(Move {
+            direction,
+            distance,
+        })
+    }
+}
+
+aoc_common::sample_tests!(Day09);
@@ -0,0 +1,8 @@
+; Draws a line going down the screen from left to right, moving the sprite a few pixels to the right
+; after each row. Every row takes exactly 40 cycles, and the program never halts, so the CPU only
+; stops once the screen is full.
+row:    setw 9          ; 1 cycle
+wait:   subw 1          ; 2 cycles, 9 times
+        jnzw wait       ; 2 cycles, 9 times
+        addx 7          ; 2 cycles
+        jmp row         ; 1 cycle
@@ -0,0 +1,348 @@
+/*!
+ * Assembly language of the CPU, which the puzzle input is already written in.
+ *
+ * A program has an instruction per line. Instructions that work on a register end with its name,
+ * like the puzzle's `addx`, and there are 4 registers: `w`, `x`, `y` and `z`. X is where the CRT
+ * draws the sprite, and it starts at 1 while the others start at 0. Operands `V` can be a number
+ * or the name of a register.
+ *
+ * | Instruction | Cycles | Effect                             |
+ * |-------------|--------|------------------------------------|
+ * | `noop`      | 1      | Nothing                            |
+ * | `addR V`    | 2      | Adds V to R                        |
+ * | `subR V`    | 2      | Subtracts V from R                 |
+ * | `mulR V`    | 3      | Multiplies R by V                  |
+ * | `setR V`    | 1      | Sets R to V                        |
+ * | `jmp L`     | 1      | Goes on from label L               |
+ * | `jzR L`     | 2      | Goes on from label L if R is 0     |
+ * | `jnzR L`    | 2      | Goes on from label L unless R is 0 |
+ *
+ * Registers are 64 bits, and `add`, `sub` and `mul` all wrap around on overflow.
+ *
+ * Labels are a name followed by `:`, either on their own line or before an instruction, and
+ * anything after a `;` is a comment:
+ *
+ * ```text
+ *         sety 3
+ * loop:   addx 2      ; x = 1 + 2 * 3
+ *         suby 1
+ *         jnzy loop
+ * ```
+ *
+ * The CPU halts after the last instruction, or when jumping to a label after it.
+ */
+use std::collections::{BTreeSet, HashMap};
+
+use aoc_common::{parse_number, Input, ParseError, ParseResult};
+
+use crate::cpu::{Instruction, Operand, Register};
+
+/// Turns the source of a program into its instructions, with errors pointing at what's wrong.
+pub fn assemble(input: &Input) -> ParseResult<Vec<Instruction>> {
+    let lines: Vec<Line> = input
+        .numbered_lines()
+        .map(|(number, text)| Line::split(text).map_err(|error| input.locate(error, number)))
+        .collect::<ParseResult<_>>()?;
+
+    // Labels can be used before they're defined, so they're all collected first
+    let mut labels = HashMap::new();
+    let mut index = 0;
+
+    for (number, line) in (1..).zip(&lines) {
+        if let Some(label) = line.label {
+            if let Some((_, previous)) = labels.insert(label, (index, number)) {
+                let message = format!("Label {} is already defined on line {}", label, previous);
+                return Err(input.locate(ParseError::at(message, line.text, label), number));
+            }
+        }
+
+        if line.instruction.is_some() {
+            index += 1;
+        }
+    }
+
+    (1..)
+        .zip(&lines)
+        .filter_map(|(number, line)| Some((number, line, line.instruction?)))
+        .map(|(number, line, instruction)| {
+            parse_instruction(line.text, instruction, &labels)
+                .map_err(|error| input.locate(error, number))
+        })
+        .collect()
+}
+
+// A line of source, without its comment
+struct Line<'a> {
+    text: &'a str,
+    label: Option<&'a str>,
+    instruction: Option<&'a str>,
+}
+
+impl<'a> Line<'a> {
+    fn split(text: &'a str) -> ParseResult<Self> {
+        let code = text.split(';').next().unwrap_or_default();
+
+        let (label, rest) = match code.split_once(':') {
+            Some((label, rest)) => {
+                let label = label.trim();
+                if !is_label(label) {
+                    return Err(ParseError::at(
+                        format!(
+                            "Invalid label {:?}, labels are letters, digits and _",
+                            label
+                        ),
+                        text,
+                        label,
+                    ));
+                }
+                (Some(label), rest)
+            }
+            None => (None, code),
+        };
+
+        let instruction = rest.trim();
+
+        Ok(Line {
+            text,
+            label,
+            instruction: (!instruction.is_empty()).then_some(instruction),
+        })
+    }
+}
+
+fn is_label(name: &str) -> bool {
+    name.starts_with(|char: char| char.is_ascii_alphabetic() || char == '_')
+        && name
+            .chars()
+            .all(|char| char.is_ascii_alphanumeric() || char == '_')
+}
+
+fn parse_instruction(
+    text: &str,
+    instruction: &str,
+    labels: &HashMap<&str, (usize, usize)>,
+) -> ParseResult<Instruction> {
+    let mut words = instruction.split_whitespace();
+    let mnemonic = words.next().unwrap_or_default();
+    let operands: Vec<&str> = words.collect();
+
+    let expect_operands = |count: usize| {
+        if operands.len() == count {
+            return Ok(());
+        }
+
+        let message = format!(
+            "{} takes {} operand{}, not {}",
+            mnemonic,
+            count,
+            if count == 1 { "" } else { "s" },
+            operands.len()
+        );
+        // Points at the extra operands, or at the whole instruction when some are missing
+        let part = operands.get(count).copied().unwrap_or(instruction);
+        Err(ParseError::at(message, text, part))
+    };
+
+    let label = |name: &str| {
+        labels
+            .get(name)
+            .map(|&(index, _)| index)
+            .ok_or_else(|| ParseError::at(format!("Unknown label {}", name), text, name))
+    };
+
+    let register = |name: &str| {
+        name.parse::<Register>()
+            .map_err(|error| error.within(text, name))
+    };
+
+    if mnemonic == "noop" {
+        expect_operands(0)?;
+        return Ok(Instruction::Noop);
+    }
+
+    if mnemonic == "jmp" {
+        expect_operands(1)?;
+        return Ok(Instruction::Jump(label(operands[0])?));
+    }
+
+    for prefix in ["add", "sub", "mul", "set", "jz", "jnz"] {
+        let Some(suffix) = mnemonic.strip_prefix(prefix) else {
+            continue;
+        };
+        // Register names are a single letter, so e.g. `address` isn't an `add`
+        if suffix.chars().count() != 1 {
+            continue;
+        }
+
+        let register = register(suffix)?;
+        expect_operands(1)?;
+        let operand = operands[0];
+
+        return Ok(match prefix {
+            "jz" => Instruction::JumpIfZero(register, label(operand)?),
+            "jnz" => Instruction::JumpIfNotZero(register, label(operand)?),
+            _ => {
+                let operand = parse_operand(text, operand)?;
+                match prefix {
+                    "add" => Instruction::Add(register, operand),
+                    "sub" => Instruction::Sub(register, operand),
+                    "mul" => Instruction::Mul(register, operand),
+                    _ => Instruction::Set(register, operand),
+                }
+            }
+        });
+    }
+
+    Err(ParseError::at(
+        format!("Unknown instruction {}", mnemonic),
+        text,
+        mnemonic,
+    ))
+}
+
+fn parse_operand(text: &str, operand: &str) -> ParseResult<Operand> {
+    if operand.starts_with(|char: char| char.is_ascii_alphabetic()) {
+        let register = operand
+            .parse()
+            .map_err(|error: ParseError| error.within(text, operand))?;
+        Ok(Operand::Register(register))
+    } else {
+        Ok(Operand::Value(parse_number(text, operand)?))
+    }
+}
+
+/**
+ * Writes a program back as source, which assembles to the same instructions.
+ *
+ * Names of labels are lost when assembling, so the targets of jumps are named `label1`, `label2`...
+ * in the order they appear.
+ */
+pub fn disassemble(program: &[Instruction]) -> String {
+    let names = label_names(program);
+    let instructions = listing(program);
+    let mut lines = vec![];
+
+    for index in 0..=program.len() {
+        if let Some(name) = names.get(&index) {
+            lines.push(format!("{}:", name));
+        }
+
+        lines.extend(instructions.get(index).cloned());
+    }
+
+    lines.join("\n")
+}
+
+/// The source of each instruction of `program`, with labels named like `disassemble` does.
+pub fn listing(program: &[Instruction]) -> Vec<String> {
+    let names = label_names(program);
+
+    program
+        .iter()
+        .map(|instruction| match *instruction {
+            Instruction::Noop => "noop".to_string(),
+            Instruction::Add(register, operand) => format!("add{} {}", register, operand),
+            Instruction::Sub(register, operand) => format!("sub{} {}", register, operand),
+            Instruction::Mul(register, operand) => format!("mul{} {}", register, operand),
+            Instruction::Set(register, operand) => format!("set{} {}", register, operand),
+            Instruction::Jump(target) => format!("jmp {}", names[&target]),
+            Instruction::JumpIfZero(register, target) => {
+                format!("jz{} {}", register, names[&target])
+            }
+            Instruction::JumpIfNotZero(register, target) => {
+                format!("jnz{} {}", register, names[&target])
+            }
+        })
+        .collect()
+}
+
+fn label_names(program: &[Instruction]) -> HashMap<usize, String> {
+    let targets: BTreeSet<usize> = program
+        .iter()
+        .filter_map(|instruction| instruction.target())
+        .collect();
+
+    (1..)
+        .zip(targets)
+        .map(|(number, target)| (target, format!("label{}", number)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assemble_text(text: &str) -> ParseResult<Vec<Instruction>> {
+        assemble(&Input::from_text(text))
+    }
+
+    #[test]
+    fn assembles_labels_and_comments() {
+        let program = assemble_text(
+            "\
+        sety 3    ; counter
+loop:   addx 2
+        suby 1
+        jnzy loop
+        mulx y
+end:",
+        )
+        .unwrap();
+
+        assert_eq!(
+            program,
+            vec![
+                Instruction::Set(Register::Y, Operand::Value(3)),
+                Instruction::Add(Register::X, Operand::Value(2)),
+                Instruction::Sub(Register::Y, Operand::Value(1)),
+                Instruction::JumpIfNotZero(Register::Y, 1),
+                Instruction::Mul(Register::X, Operand::Register(Register::Y)),
+            ]
+        );
+    }
+
+    #[test]
+    fn points_at_mistakes() {
+        let error = |text| {
+            let error = assemble_text(text).unwrap_err();
+            (error.message().to_string(), error.line(), error.column())
+        };
+
+        assert_eq!(
+            error("noop\naddq 3"),
+            (
+                "Unknown register q, they're w, x, y and z".into(),
+                Some(2),
+                4
+            )
+        );
+        assert_eq!(
+            error("jmp nowhere"),
+            ("Unknown label nowhere".into(), Some(1), 5)
+        );
+        assert_eq!(
+            error("a: noop\na: noop"),
+            ("Label a is already defined on line 1".into(), Some(2), 1)
+        );
+        assert_eq!(
+            error("addx 1 2"),
+            ("addx takes 1 operand, not 2".into(), Some(1), 8)
+        );
+        assert_eq!(
+            error("  jumpx a"),
+            ("Unknown instruction jumpx".into(), Some(1), 3)
+        );
+    }
+
+    #[test]
+    fn disassembles_to_the_same_program() {
+        let program = assemble_text("start: addx -2\njzw start\njmp end\nnoop\nend:").unwrap();
+        let source = disassemble(&program);
+
+        assert_eq!(
+            source,
+            "label1:\naddx -2\njzw label1\njmp label2\nnoop\nlabel2:"
+        );
+        assert_eq!(assemble_text(&source), Ok(program));
+    }
+}
@@ -0,0 +1,388 @@
+use std::fmt;
+use std::ops::ControlFlow;
+use std::str::FromStr;
+
+use aoc_common::ParseError;
+use tracing::debug;
+
+use crate::crt::Crt;
+
+/**
+ * The handheld's CPU, which ticks `crt` once per cycle while running a program.
+ *
+ * Instructions take as many cycles as `Instruction::cycles` says, and their effect only shows once
+ * the last of them is over. That's why registers can be looked at both during a cycle (which is
+ * what the CRT sees) and after it.
+ */
+pub struct Cpu<C: Crt> {
+    /// Cycles that are over
+    cycles: usize,
+    registers: [isize; Register::ALL.len()],
+    program: Vec<Instruction>,
+    state: State,
+    crt: C,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum State {
+    /// In the middle of the instruction at `pointer`, which has `remaining` cycles to go
+    Running { pointer: usize, remaining: usize },
+    /// Past the end of the program, so nothing happens anymore
+    Halted,
+}
+
+impl<C: Crt> Cpu<C> {
+    pub fn new(crt: C) -> Self {
+        Cpu {
+            cycles: 0,
+            registers: Register::INITIAL,
+            program: vec![],
+            state: State::Halted,
+            crt,
+        }
+    }
+
+    /// Replaces the program and starts it from the beginning, with the registers reset.
+    pub fn load(&mut self, program: Vec<Instruction>) {
+        self.program = program;
+        self.cycles = 0;
+        self.registers = Register::INITIAL;
+        self.state = self.start(0);
+    }
+
+    // State at the start of the instruction at `pointer`, which halts past the end of the program
+    fn start(&self, pointer: usize) -> State {
+        match self.program.get(pointer) {
+            Some(instruction) => State::Running {
+                pointer,
+                remaining: instruction.cycles(),
+            },
+            None => State::Halted,
+        }
+    }
+
+    /// Runs a single cycle, unless the CPU is halted. Returns whether it ran one.
+    pub fn step(&mut self) -> bool {
+        let State::Running { pointer, remaining } = self.state else {
+            return false;
+        };
+
+        self.cycles += 1;
+        self.crt.tick(self.cycles, self.register_x());
+
+        self.state = if remaining > 1 {
+            State::Running {
+                pointer,
+                remaining: remaining - 1,
+            }
+        } else {
+            let next = self.execute(self.program[pointer], pointer);
+            self.start(next)
+        };
+
+        true
+    }
+
+    // Applies the effect of `instruction`, returning where to go next
+    fn execute(&mut self, instruction: Instruction, pointer: usize) -> usize {
+        let next = pointer + 1;
+
+        let (register, value) = match instruction {
+            Instruction::Noop => return next,
+            Instruction::Add(register, operand) => (
+                register,
+                self.register(register).wrapping_add(self.value(operand)),
+            ),
+            Instruction::Sub(register, operand) => (
+                register,
+                self.register(register).wrapping_sub(self.value(operand)),
+            ),
+            Instruction::Mul(register, operand) => (
+                register,
+                self.register(register).wrapping_mul(self.value(operand)),
+            ),
+            Instruction::Set(register, operand) => (register, self.value(operand)),
+            Instruction::Jump(target) => return target,
+            Instruction::JumpIfZero(register, target) => {
+                return if self.register(register) == 0 {
+                    target
+                } else {
+                    next
+                };
+            }
+            Instruction::JumpIfNotZero(register, target) => {
+                return if self.register(register) != 0 {
+                    target
+                } else {
+                    next
+                };
+            }
+        };
+
+        self.registers[register.index()] = value;
+        debug!(cycle = self.cycles, %register, value, "Register changed");
+
+        next
+    }
+
+    fn value(&self, operand: Operand) -> isize {
+        match operand {
+            Operand::Value(value) => value,
+            Operand::Register(register) => self.register(register),
+        }
+    }
+
+    /// Runs until the program is over.
+    pub fn run(&mut self) {
+        self.run_with(|_| ControlFlow::Continue(()));
+    }
+
+    /// Runs until the program is over, or until `observe` (called after every cycle) breaks.
+    pub fn run_with(&mut self, mut observe: impl FnMut(&Self) -> ControlFlow<()>) {
+        while self.step() {
+            if observe(self).is_break() {
+                break;
+            }
+        }
+    }
+
+    /// Runs until `cycle` is over, or the program is, whichever comes first.
+    pub fn run_until_after(&mut self, cycle: usize) {
+        while self.cycles < cycle && self.step() {}
+    }
+
+    /**
+     * Value of the X register during `cycle`, running until it starts. Registers keep their last
+     * value once the CPU is halted, and there's no going back, so this is `None` for cycles that
+     * already started.
+     */
+    pub fn x_during(&mut self, cycle: usize) -> Option<isize> {
+        let before = cycle.checked_sub(1)?;
+        self.x_after(before)
+    }
+
+    /// Value of the X register after `cycle` is over, like `x_during`.
+    pub fn x_after(&mut self, cycle: usize) -> Option<isize> {
+        if self.cycles > cycle {
+            return None;
+        }
+
+        self.run_until_after(cycle);
+        Some(self.register_x())
+    }
+
+    /// How many cycles are over.
+    pub fn cycles(&self) -> usize {
+        self.cycles
+    }
+
+    pub fn register(&self, register: Register) -> isize {
+        self.registers[register.index()]
+    }
+
+    /// The register the sprite is drawn at.
+    pub fn register_x(&self) -> isize {
+        self.register(Register::X)
+    }
+
+    pub fn program(&self) -> &[Instruction] {
+        &self.program
+    }
+
+    pub fn state(&self) -> State {
+        self.state
+    }
+
+    pub fn is_halted(&self) -> bool {
+        self.state == State::Halted
+    }
+
+    pub fn crt(&self) -> &C {
+        &self.crt
+    }
+}
+
+/// Instructions, with the targets of jumps as indices in the program. See `asm` for what they do.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Noop,
+    Add(Register, Operand),
+    Sub(Register, Operand),
+    Mul(Register, Operand),
+    Set(Register, Operand),
+    Jump(usize),
+    JumpIfZero(Register, usize),
+    JumpIfNotZero(Register, usize),
+}
+
+impl Instruction {
+    /// How many cycles the instruction takes to complete.
+    pub fn cycles(&self) -> usize {
+        match self {
+            Instruction::Noop | Instruction::Set(..) | Instruction::Jump(_) => 1,
+            Instruction::Add(..)
+            | Instruction::Sub(..)
+            | Instruction::JumpIfZero(..)
+            | Instruction::JumpIfNotZero(..) => 2,
+            Instruction::Mul(..) => 3,
+        }
+    }
+
+    /// Where the instruction may jump to, if it's a jump.
+    pub fn target(&self) -> Option<usize> {
+        match *self {
+            Instruction::Jump(target)
+            | Instruction::JumpIfZero(_, target)
+            | Instruction::JumpIfNotZero(_, target) => Some(target),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Register {
+    W,
+    X,
+    Y,
+    Z,
+}
+
+impl Register {
+    pub const ALL: [Register; 4] = [Register::W, Register::X, Register::Y, Register::Z];
+    // X starts at 1 and the others at 0
+    const INITIAL: [isize; 4] = [0, 1, 0, 0];
+
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl FromStr for Register {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "w" => Ok(Register::W),
+            "x" => Ok(Register::X),
+            "y" => Ok(Register::Y),
+            "z" => Ok(Register::Z),
+            _ => Err(ParseError::new(
+                format!("Unknown register {}, they're w, x, y and z", s),
+                s,
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Register {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Register::W => "w",
+            Register::X => "x",
+            Register::Y => "y",
+            Register::Z => "z",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// What arithmetic instructions work with: either a number, or the value of a register.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Operand {
+    Value(isize),
+    Register(Register),
+}
+
+impl fmt::Display for Operand {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Operand::Value(value) => write!(f, "{}", value),
+            Operand::Register(register) => write!(f, "{}", register),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::crt::NoCrt;
+
+    fn cpu(program: Vec<Instruction>) -> Cpu<NoCrt> {
+        let mut cpu = Cpu::new(NoCrt);
+        cpu.load(program);
+        cpu
+    }
+
+    // The small program from the puzzle
+    fn small_program() -> Vec<Instruction> {
+        vec![
+            Instruction::Noop,
+            Instruction::Add(Register::X, Operand::Value(3)),
+            Instruction::Add(Register::X, Operand::Value(-5)),
+        ]
+    }
+
+    #[test]
+    fn changes_registers_after_the_last_cycle_of_an_instruction() {
+        let mut cpu = cpu(small_program());
+
+        let during: Vec<_> = (1..=5).map(|cycle| cpu.x_during(cycle)).collect();
+        assert_eq!(during, [1, 1, 1, 4, 4].map(Some));
+        assert_eq!(cpu.x_after(5), Some(-1));
+        assert_eq!(cpu.x_during(3), None);
+    }
+
+    #[test]
+    fn halts_at_the_end_of_the_program() {
+        let mut cpu = cpu(small_program());
+        cpu.run_until_after(2);
+        assert_eq!(
+            cpu.state(),
+            State::Running {
+                pointer: 1,
+                remaining: 1
+            }
+        );
+
+        cpu.run();
+        assert!(cpu.is_halted());
+        assert_eq!(cpu.cycles(), 5);
+        assert!(!cpu.step());
+        assert_eq!(cpu.x_after(100), Some(-1));
+    }
+
+    #[test]
+    fn loops_with_conditional_jumps() {
+        // x = 3 * 4, by adding 3 to x 4 times
+        let mut cpu = cpu(vec![
+            Instruction::Set(Register::X, Operand::Value(0)),
+            Instruction::Set(Register::Y, Operand::Value(4)),
+            Instruction::Add(Register::X, Operand::Value(3)),
+            Instruction::Sub(Register::Y, Operand::Value(1)),
+            Instruction::JumpIfNotZero(Register::Y, 2),
+            Instruction::Mul(Register::X, Operand::Register(Register::Y)),
+        ]);
+        cpu.run_until_after(2 + 4 * 6);
+
+        assert_eq!(cpu.register_x(), 12);
+        assert_eq!(cpu.register(Register::Y), 0);
+
+        cpu.run();
+        assert_eq!(cpu.register_x(), 0);
+        assert_eq!(cpu.cycles(), 2 + 4 * 6 + 3);
+    }
+
+    #[test]
+    fn wraps_around_on_overflow() {
+        let mut cpu = cpu(vec![
+            Instruction::Set(Register::X, Operand::Value(isize::MAX)),
+            Instruction::Add(Register::X, Operand::Value(1)),
+            Instruction::Set(Register::Y, Operand::Value(isize::MIN)),
+            Instruction::Sub(Register::Y, Operand::Value(1)),
+        ]);
+        cpu.run();
+
+        assert_eq!(cpu.register_x(), isize::MIN);
+        assert_eq!(cpu.register(Register::Y), isize::MAX);
+    }
+}
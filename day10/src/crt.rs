@@ -0,0 +1,174 @@
+/*!
+ * Displays the CPU draws on, one pixel per cycle.
+ *
+ * The CRT draws from left to right and top to bottom, and a pixel is lit when the 3 pixels wide
+ * sprite, centered on the value of the X register, covers it. The CPU only says which cycle it is
+ * and where the sprite is, and each display decides what to do with that.
+ */
+use std::fmt;
+use std::io::{self, Write};
+
+use aoc_common::Grid;
+
+pub const SCREEN_WIDTH: usize = 40;
+pub const SCREEN_HEIGHT: usize = 6;
+
+pub trait Crt {
+    /// Draws the pixel of `cycle` (counting from 1), with the sprite centered on `sprite`.
+    fn tick(&mut self, cycle: usize, sprite: isize);
+}
+
+/// Where the pixel drawn during `cycle` is on the screen, and whether it's lit.
+pub fn pixel(cycle: usize, sprite: isize) -> ((usize, usize), bool) {
+    let index = cycle - 1;
+    let x = index % SCREEN_WIDTH;
+
+    (
+        (x, index / SCREEN_WIDTH),
+        (x as isize).abs_diff(sprite) <= 1,
+    )
+}
+
+fn symbol(lit: bool) -> char {
+    if lit {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+/// Keeps the pixels in memory, to read the letters on the screen or draw it as a frame.
+#[derive(Debug, Clone)]
+pub struct Framebuffer {
+    pixels: Grid<bool>,
+    drawn: usize,
+}
+
+impl Framebuffer {
+    pub fn new() -> Self {
+        Framebuffer {
+            pixels: Grid::new(SCREEN_WIDTH, SCREEN_HEIGHT, false),
+            drawn: 0,
+        }
+    }
+
+    pub fn pixels(&self) -> &Grid<bool> {
+        &self.pixels
+    }
+
+    /// The whole screen, with the pixels that weren't drawn yet left blank.
+    pub fn cells(&self) -> Grid<char> {
+        Grid::from_fn(SCREEN_WIDTH, SCREEN_HEIGHT, |(x, y)| {
+            if y * SCREEN_WIDTH + x < self.drawn {
+                symbol(self.pixels[(x, y)])
+            } else {
+                ' '
+            }
+        })
+    }
+}
+
+impl Default for Framebuffer {
+    fn default() -> Self {
+        Framebuffer::new()
+    }
+}
+
+/// Cycles past the last pixel don't draw anything.
+impl Crt for Framebuffer {
+    fn tick(&mut self, cycle: usize, sprite: isize) {
+        let (position, lit) = pixel(cycle, sprite);
+
+        if let Some(pixel) = self.pixels.get_mut(position) {
+            *pixel = lit;
+            self.drawn = self.drawn.max(cycle);
+        }
+    }
+}
+
+/// Lit pixels as `#` and dark ones as `.`, one line per row.
+impl fmt::Display for Framebuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.pixels.map(|&lit| symbol(lit)))
+    }
+}
+
+/// Writes each pixel as soon as it's drawn, as `#` or `.`, going to the next line after each row.
+pub struct Printer<W: Write = io::Stdout> {
+    out: W,
+}
+
+impl Printer {
+    pub fn stdout() -> Self {
+        Printer { out: io::stdout() }
+    }
+}
+
+impl<W: Write> Printer<W> {
+    pub fn new(out: W) -> Self {
+        Printer { out }
+    }
+
+    pub fn into_inner(self) -> W {
+        self.out
+    }
+}
+
+// The screen is only something to look at, so failing to write it doesn't stop the CPU
+impl<W: Write> Crt for Printer<W> {
+    fn tick(&mut self, cycle: usize, sprite: isize) {
+        if cycle > SCREEN_WIDTH * SCREEN_HEIGHT {
+            return;
+        }
+
+        let ((x, _), lit) = pixel(cycle, sprite);
+        let _ = write!(self.out, "{}", symbol(lit));
+
+        if x == SCREEN_WIDTH - 1 {
+            let _ = writeln!(self.out);
+            let _ = self.out.flush();
+        }
+    }
+}
+
+/// Doesn't draw anything, for when only the registers matter.
+pub struct NoCrt;
+
+impl Crt for NoCrt {
+    fn tick(&mut self, _cycle: usize, _sprite: isize) {}
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The sprite stays at 0, so only the first 2 pixels of each row are lit
+    fn draw(crt: &mut impl Crt, cycles: usize) {
+        for cycle in 1..=cycles {
+            crt.tick(cycle, 0);
+        }
+    }
+
+    #[test]
+    fn keeps_pixels_in_framebuffer() {
+        let mut framebuffer = Framebuffer::new();
+        draw(&mut framebuffer, 42);
+
+        assert!(framebuffer.pixels()[(1, 0)]);
+        assert!(!framebuffer.pixels()[(2, 0)]);
+        assert!(framebuffer.pixels()[(1, 1)]);
+
+        let cells = framebuffer.cells();
+        assert_eq!(cells[(1, 1)], '#');
+        assert_eq!(cells[(2, 1)], ' ');
+    }
+
+    #[test]
+    fn prints_rows_as_they_are_drawn() {
+        let mut printer = Printer::new(vec![]);
+        draw(&mut printer, 41);
+
+        let text = String::from_utf8(printer.into_inner()).unwrap();
+        assert_eq!(text, format!("##{}\n#", ".".repeat(38)));
+    }
+}
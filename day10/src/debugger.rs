@@ -0,0 +1,566 @@
+/*!
+ * Interactive debugger for programs of the CPU, to find out why the CRT shows what it shows.
+ *
+ * It reads commands one line at a time, and shows the registers, the next instruction, the next
+ * pixel and the screen so far each time the CPU stops:
+ *
+ * | Command              | Effect                                                        |
+ * |----------------------|---------------------------------------------------------------|
+ * | `break cycle N`      | Stops once cycle N is over                                    |
+ * | `break pc N`         | Stops before the instruction at index N (counting from 0)     |
+ * | `break R OP V`       | Stops when the condition becomes true, e.g. `break x > 30`    |
+ * | `delete N`           | Removes breakpoint N                                          |
+ * | `breakpoints`        | Lists the breakpoints                                         |
+ * | `step [N]`           | Runs N cycles (1 by default)                                  |
+ * | `continue`           | Runs until a breakpoint, the end of the program or the screen |
+ * | `print`              | Shows where the CPU is again                                  |
+ * | `trace FILE`         | Writes every cycle run so far to FILE, as CSV                 |
+ * | `help`               | Lists the commands                                            |
+ * | `quit`               | Stops debugging                                               |
+ *
+ * Conditions compare a register with a number or another register, with one of `==`, `!=`, `<`,
+ * `<=`, `>` or `>=`. Commands can be shortened to their first letter, and an empty line repeats
+ * the previous `step` or `continue`.
+ */
+use std::fmt;
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::str::FromStr;
+
+use crate::asm;
+use crate::cpu::{Cpu, Instruction, Operand, Register, State};
+use crate::crt::{self, Framebuffer, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+const HELP: &str = "\
+break cycle N      Stops once cycle N is over
+break pc N         Stops before the instruction at index N
+break R OP V       Stops when the condition becomes true, e.g. break x > 30
+delete N           Removes breakpoint N
+breakpoints        Lists the breakpoints
+step [N]           Runs N cycles (1 by default)
+continue           Runs until a breakpoint, the end of the program or the screen
+print              Shows where the CPU is again
+trace FILE         Writes every cycle run so far to FILE, as CSV
+help               Lists the commands
+quit               Stops debugging";
+
+/// How long `continue` goes on without hitting anything, since programs can loop forever.
+const MAX_CONTINUE: usize = 100_000;
+
+pub struct Debugger {
+    cpu: Cpu<Framebuffer>,
+    listing: Vec<String>,
+    // Numbered from 1, and deleted ones are kept as None so the others keep their number
+    breakpoints: Vec<Option<Breakpoint>>,
+    trace: Vec<TraceEntry>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Breakpoint {
+    Cycle(usize),
+    Instruction(usize),
+    Condition(Register, Comparison, Operand),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Comparison {
+    Equal,
+    NotEqual,
+    Less,
+    LessOrEqual,
+    Greater,
+    GreaterOrEqual,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Break(Breakpoint),
+    Delete(usize),
+    Breakpoints,
+    Step(usize),
+    Continue,
+    Print,
+    Trace(String),
+    Help,
+    Quit,
+}
+
+// What the CPU did during a cycle, with the registers as they were during it
+struct TraceEntry {
+    cycle: usize,
+    pointer: usize,
+    registers: [isize; Register::ALL.len()],
+    lit: Option<bool>,
+}
+
+// Why the CPU stopped running
+enum Stop {
+    Steps,
+    Breakpoint(usize),
+    Halted,
+    ScreenFull,
+    TooLong,
+}
+
+impl Debugger {
+    pub fn new(program: Vec<Instruction>) -> Self {
+        let mut cpu = Cpu::new(Framebuffer::new());
+        let listing = asm::listing(&program);
+        cpu.load(program);
+
+        Debugger {
+            cpu,
+            listing,
+            breakpoints: vec![],
+            trace: vec![],
+        }
+    }
+
+    /// Runs commands read from `input` until it ends or says to quit, writing what happens to `out`.
+    pub fn run(&mut self, input: impl BufRead, mut out: impl Write) -> io::Result<()> {
+        let mut lines = input.lines();
+        let mut previous: Option<Command> = None;
+
+        self.dump(&mut out)?;
+
+        loop {
+            write!(out, "(debug) ")?;
+            out.flush()?;
+
+            let Some(line) = lines.next().transpose()? else {
+                return Ok(());
+            };
+
+            let command = match (line.trim(), &previous) {
+                ("", Some(previous)) => Ok(previous.clone()),
+                (line, _) => line.parse::<Command>(),
+            };
+
+            match command {
+                Ok(Command::Quit) => return Ok(()),
+                Ok(command) => {
+                    self.execute(&command, &mut out)?;
+                    if command.is_repeatable() {
+                        previous = Some(command);
+                    }
+                }
+                Err(error) => writeln!(out, "{}", error)?,
+            }
+        }
+    }
+
+    fn execute(&mut self, command: &Command, out: &mut impl Write) -> io::Result<()> {
+        match *command {
+            Command::Break(breakpoint) => {
+                self.breakpoints.push(Some(breakpoint));
+                writeln!(out, "Breakpoint {}: {}", self.breakpoints.len(), breakpoint)
+            }
+            Command::Delete(number) => match self.breakpoints.get_mut(number.wrapping_sub(1)) {
+                Some(breakpoint @ Some(_)) => {
+                    *breakpoint = None;
+                    writeln!(out, "Deleted breakpoint {}", number)
+                }
+                _ => writeln!(out, "There's no breakpoint {}", number),
+            },
+            Command::Breakpoints => {
+                for (number, breakpoint) in (1..).zip(&self.breakpoints) {
+                    if let Some(breakpoint) = breakpoint {
+                        writeln!(out, "{}: {}", number, breakpoint)?;
+                    }
+                }
+                Ok(())
+            }
+            Command::Step(cycles) => {
+                let stop = self.resume(Some(cycles));
+                self.report(stop, out)
+            }
+            Command::Continue => {
+                let stop = self.resume(None);
+                self.report(stop, out)
+            }
+            Command::Print => self.dump(out),
+            Command::Trace(ref path) => match fs::write(path, self.trace_csv()) {
+                Ok(()) => writeln!(out, "Wrote {} cycles to {}", self.trace.len(), path),
+                Err(error) => writeln!(out, "Unable to write {}: {}", path, error),
+            },
+            Command::Help => writeln!(out, "{}", HELP),
+            Command::Quit => Ok(()),
+        }
+    }
+
+    // Runs `cycles` cycles, or until something stops it when continuing
+    fn resume(&mut self, cycles: Option<usize>) -> Stop {
+        let screen_cycles = SCREEN_WIDTH * SCREEN_HEIGHT;
+        let screen_was_full = self.cpu.cycles() >= screen_cycles;
+
+        let limit = cycles.unwrap_or(MAX_CONTINUE);
+
+        for _ in 0..limit {
+            let conditions_before = self.conditions();
+            if !self.step() {
+                return Stop::Halted;
+            }
+
+            if let Some(number) = self.hit_breakpoint(&conditions_before) {
+                return Stop::Breakpoint(number);
+            }
+
+            if cycles.is_none() && !screen_was_full && self.cpu.cycles() == screen_cycles {
+                return Stop::ScreenFull;
+            }
+        }
+
+        match cycles {
+            Some(_) => Stop::Steps,
+            None => Stop::TooLong,
+        }
+    }
+
+    fn step(&mut self) -> bool {
+        let State::Running { pointer, .. } = self.cpu.state() else {
+            return false;
+        };
+
+        let cycle = self.cpu.cycles() + 1;
+        let ((_, y), lit) = crt::pixel(cycle, self.cpu.register_x());
+
+        self.trace.push(TraceEntry {
+            cycle,
+            pointer,
+            registers: Register::ALL.map(|register| self.cpu.register(register)),
+            lit: (y < SCREEN_HEIGHT).then_some(lit),
+        });
+
+        self.cpu.step()
+    }
+
+    // Whether each breakpoint's condition is true, to only stop when one becomes true
+    fn conditions(&self) -> Vec<bool> {
+        self.breakpoints
+            .iter()
+            .map(|breakpoint| match breakpoint {
+                Some(Breakpoint::Condition(register, comparison, operand)) => {
+                    self.holds(*register, *comparison, *operand)
+                }
+                _ => false,
+            })
+            .collect()
+    }
+
+    fn holds(&self, register: Register, comparison: Comparison, operand: Operand) -> bool {
+        let value = self.cpu.register(register);
+        let other = match operand {
+            Operand::Value(value) => value,
+            Operand::Register(register) => self.cpu.register(register),
+        };
+
+        match comparison {
+            Comparison::Equal => value == other,
+            Comparison::NotEqual => value != other,
+            Comparison::Less => value < other,
+            Comparison::LessOrEqual => value <= other,
+            Comparison::Greater => value > other,
+            Comparison::GreaterOrEqual => value >= other,
+        }
+    }
+
+    fn hit_breakpoint(&self, conditions_before: &[bool]) -> Option<usize> {
+        let conditions = self.conditions();
+
+        (1..)
+            .zip(&self.breakpoints)
+            .zip(conditions.iter().zip(conditions_before))
+            .find_map(|((number, breakpoint), (&now, &before))| {
+                let hit = match (*breakpoint)? {
+                    Breakpoint::Cycle(cycle) => self.cpu.cycles() == cycle,
+                    Breakpoint::Instruction(index) => self.starts(index),
+                    Breakpoint::Condition(..) => now && !before,
+                };
+                hit.then_some(number)
+            })
+    }
+
+    // Whether the CPU is about to start the instruction at `index`
+    fn starts(&self, index: usize) -> bool {
+        match self.cpu.state() {
+            State::Running { pointer, remaining } => {
+                pointer == index && remaining == self.cpu.program()[pointer].cycles()
+            }
+            State::Halted => false,
+        }
+    }
+
+    fn report(&self, stop: Stop, out: &mut impl Write) -> io::Result<()> {
+        match stop {
+            Stop::Steps => {}
+            Stop::Breakpoint(number) => {
+                let breakpoint = self.breakpoints[number - 1].expect("Deleted breakpoint hit");
+                writeln!(out, "Stopped at breakpoint {}: {}", number, breakpoint)?;
+            }
+            Stop::Halted => writeln!(out, "The program is over")?,
+            Stop::ScreenFull => writeln!(out, "The screen is full")?,
+            Stop::TooLong => writeln!(
+                out,
+                "Stopped after {} cycles without reaching a breakpoint",
+                MAX_CONTINUE
+            )?,
+        }
+
+        self.dump(out)
+    }
+
+    fn dump(&self, out: &mut impl Write) -> io::Result<()> {
+        let registers: Vec<String> = Register::ALL
+            .iter()
+            .map(|&register| format!("{} = {}", register, self.cpu.register(register)))
+            .collect();
+        writeln!(
+            out,
+            "Cycle {} over, {}",
+            self.cpu.cycles(),
+            registers.join(", ")
+        )?;
+
+        match self.cpu.state() {
+            State::Running { pointer, remaining } => {
+                let instruction = self.cpu.program()[pointer];
+                writeln!(
+                    out,
+                    "Next: {} {} ({} of {} cycles left)",
+                    pointer,
+                    self.listing[pointer],
+                    remaining,
+                    instruction.cycles()
+                )?;
+            }
+            State::Halted => writeln!(out, "Halted")?,
+        }
+
+        let cycle = self.cpu.cycles() + 1;
+        let ((x, y), lit) = crt::pixel(cycle, self.cpu.register_x());
+        if y < SCREEN_HEIGHT {
+            let state = if lit { "lit" } else { "dark" };
+            writeln!(out, "Pixel ({}, {}) will be {}", x, y, state)?;
+        }
+
+        writeln!(out, "{}", self.cpu.crt().cells())
+    }
+
+    fn trace_csv(&self) -> String {
+        let mut csv = String::from("cycle,pointer,instruction,w,x,y,z,pixel\n");
+
+        for entry in &self.trace {
+            let registers: Vec<String> = entry.registers.iter().map(isize::to_string).collect();
+            let pixel = match entry.lit {
+                Some(true) => "#",
+                Some(false) => ".",
+                None => "",
+            };
+
+            csv += &format!(
+                "{},{},{},{},{}\n",
+                entry.cycle,
+                entry.pointer,
+                self.listing[entry.pointer],
+                registers.join(","),
+                pixel
+            );
+        }
+
+        csv
+    }
+}
+
+impl Command {
+    // Only commands that run the CPU, since repeating the others wouldn't do anything new
+    fn is_repeatable(&self) -> bool {
+        matches!(self, Command::Step(_) | Command::Continue)
+    }
+}
+
+impl FromStr for Command {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let words: Vec<&str> = s.split_whitespace().collect();
+        let number = |word: &str| {
+            word.parse::<usize>()
+                .map_err(|_| format!("Invalid number {}", word))
+        };
+
+        match words.as_slice() {
+            ["b" | "break", condition @ ..] => condition.join(" ").parse().map(Command::Break),
+            ["d" | "delete", breakpoint] => number(breakpoint).map(Command::Delete),
+            ["breakpoints"] => Ok(Command::Breakpoints),
+            ["s" | "step"] => Ok(Command::Step(1)),
+            ["s" | "step", cycles] => number(cycles).map(Command::Step),
+            ["c" | "continue"] => Ok(Command::Continue),
+            ["p" | "print"] => Ok(Command::Print),
+            ["t" | "trace", path] => Ok(Command::Trace(path.to_string())),
+            ["h" | "help"] => Ok(Command::Help),
+            ["q" | "quit"] => Ok(Command::Quit),
+            _ => Err(format!(
+                "Unknown command {:?}, the commands are break, delete, breakpoints, step, \
+                 continue, print, trace, help and quit",
+                s
+            )),
+        }
+    }
+}
+
+impl FromStr for Breakpoint {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = |word: &str| {
+            word.parse::<usize>()
+                .map_err(|_| format!("Invalid number {}", word))
+        };
+
+        match s.split_whitespace().collect::<Vec<_>>().as_slice() {
+            ["cycle", cycle] => number(cycle).map(Breakpoint::Cycle),
+            ["pc", index] => number(index).map(Breakpoint::Instruction),
+            [register, comparison, operand] => {
+                let register = register.parse().map_err(|_| unknown_register(register))?;
+                let comparison = comparison.parse()?;
+                let operand = match operand.parse() {
+                    Ok(value) => Operand::Value(value),
+                    Err(_) => {
+                        Operand::Register(operand.parse().map_err(|_| unknown_register(operand))?)
+                    }
+                };
+                Ok(Breakpoint::Condition(register, comparison, operand))
+            }
+            _ => Err(format!(
+                "Invalid breakpoint {:?}, use cycle N, pc N or a condition like x > 30",
+                s
+            )),
+        }
+    }
+}
+
+fn unknown_register(name: &str) -> String {
+    format!("Unknown register {}, they're w, x, y and z", name)
+}
+
+impl FromStr for Comparison {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "==" => Ok(Comparison::Equal),
+            "!=" => Ok(Comparison::NotEqual),
+            "<" => Ok(Comparison::Less),
+            "<=" => Ok(Comparison::LessOrEqual),
+            ">" => Ok(Comparison::Greater),
+            ">=" => Ok(Comparison::GreaterOrEqual),
+            _ => Err(format!("Unknown comparison {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Breakpoint::Cycle(cycle) => write!(f, "after cycle {}", cycle),
+            Breakpoint::Instruction(index) => write!(f, "before instruction {}", index),
+            Breakpoint::Condition(register, comparison, operand) => {
+                write!(f, "when {} {} {}", register, comparison, operand)
+            }
+        }
+    }
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Comparison::Equal => "==",
+            Comparison::NotEqual => "!=",
+            Comparison::Less => "<",
+            Comparison::LessOrEqual => "<=",
+            Comparison::Greater => ">",
+            Comparison::GreaterOrEqual => ">=",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // The small program from the puzzle
+    fn debugger() -> Debugger {
+        Debugger::new(vec![
+            Instruction::Noop,
+            Instruction::Add(Register::X, Operand::Value(3)),
+            Instruction::Add(Register::X, Operand::Value(-5)),
+        ])
+    }
+
+    fn session(debugger: &mut Debugger, commands: &str) -> String {
+        let mut out = vec![];
+        debugger.run(commands.as_bytes(), &mut out).unwrap();
+        String::from_utf8(out).unwrap()
+    }
+
+    #[test]
+    fn parses_commands() {
+        assert_eq!(
+            "break x > 30".parse(),
+            Ok(Command::Break(Breakpoint::Condition(
+                Register::X,
+                Comparison::Greater,
+                Operand::Value(30)
+            )))
+        );
+        assert_eq!(
+            "b pc 12".parse(),
+            Ok(Command::Break(Breakpoint::Instruction(12)))
+        );
+        assert_eq!("s 5".parse(), Ok(Command::Step(5)));
+        assert!("break x ~ 3".parse::<Command>().is_err());
+    }
+
+    #[test]
+    fn stops_when_a_condition_becomes_true() {
+        let mut debugger = debugger();
+        let out = session(&mut debugger, "break x >= 4\ncontinue\n");
+
+        assert!(out.contains("Stopped at breakpoint 1: when x >= 4"));
+        assert!(out.contains("Cycle 3 over, w = 0, x = 4, y = 0, z = 0"));
+        assert!(out.contains("Next: 2 addx -5 (2 of 2 cycles left)"));
+        assert!(out.contains("Pixel (3, 0) will be lit"));
+    }
+
+    #[test]
+    fn steps_and_repeats_the_last_command() {
+        let mut debugger = debugger();
+        session(&mut debugger, "break pc 2\nstep\n\n\n");
+        assert_eq!(debugger.cpu.cycles(), 3);
+
+        let out = session(&mut debugger, "c\n");
+        assert!(out.contains("The program is over"));
+        assert!(out.contains("Halted"));
+    }
+
+    #[test]
+    fn only_repeats_commands_that_run_the_cpu() {
+        let mut debugger = debugger();
+        session(&mut debugger, "step\nbreak cycle 4\n\n\n");
+
+        assert_eq!(debugger.breakpoints, [Some(Breakpoint::Cycle(4))]);
+        assert_eq!(debugger.cpu.cycles(), 3);
+    }
+
+    #[test]
+    fn traces_every_cycle() {
+        let mut debugger = debugger();
+        session(&mut debugger, "step 5\n");
+
+        let csv = debugger.trace_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 6);
+        assert_eq!(lines[4], "4,2,addx -5,0,4,0,0,#");
+    }
+}
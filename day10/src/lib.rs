@@ -0,0 +1,79 @@
+use std::ops::ControlFlow;
+
+use aoc_common::{ocr, Answer, Frame, FrameSink, Input, Part, Solution};
+use tracing::debug;
+
+use cpu::{Cpu, Instruction};
+use crt::{Framebuffer, NoCrt, SCREEN_HEIGHT, SCREEN_WIDTH};
+
+pub mod asm;
+pub mod cpu;
+pub mod crt;
+pub mod debugger;
+
+/// Programs may loop forever, but nothing is drawn after the last pixel.
+const SCREEN_CYCLES: usize = SCREEN_WIDTH * SCREEN_HEIGHT;
+
+pub struct Day10 {
+    program: Vec<Instruction>,
+}
+
+impl Solution for Day10 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let program = asm::assemble(input)?;
+
+        Ok(Day10 { program })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let mut cpu = Cpu::new(NoCrt);
+        cpu.load(self.program.clone());
+
+        let mut part_1 = 0;
+        for cycle in [20, 60, 100, 140, 180, 220] {
+            let x = cpu.x_during(cycle).expect("Cycles are in increasing order");
+            part_1 += x * cycle as isize;
+        }
+
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let mut cpu = Cpu::new(Framebuffer::new());
+        cpu.load(self.program.clone());
+        cpu.run_until_after(SCREEN_CYCLES);
+
+        let screen = cpu.crt();
+        match ocr::read(screen.pixels()) {
+            Ok(letters) => Ok(letters.into()),
+            // Some programs (like the sample) don't draw letters at all, so there's nothing to
+            // read, but a few unknown letters are most likely missing from the font
+            Err(unknown) if unknown.text.chars().all(|char| char == '?') => {
+                debug!("The screen doesn't show any letters");
+                Ok(Answer::Rendering(screen.to_string()))
+            }
+            Err(unknown) => Err(unknown.into()),
+        }
+    }
+
+    // Both parts run the same program, so they have the same animation
+    fn animate(&self, _part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let mut cpu = Cpu::new(Framebuffer::new());
+        cpu.load(self.program.clone());
+        // The frame of the last pixel is shown too, and then there's nothing more to draw
+        cpu.run_with(|cpu| match sink.show(frame(cpu)) {
+            _ if cpu.cycles() >= SCREEN_CYCLES => ControlFlow::Break(()),
+            flow => flow,
+        });
+
+        Ok(())
+    }
+}
+
+fn frame(cpu: &Cpu<Framebuffer>) -> Frame {
+    let caption = format!("Cycle {}, X = {}", cpu.cycles(), cpu.register_x());
+    Frame::new(caption, cpu.crt().cells())
+}
+
+aoc_common::sample_tests!(Day10);
@@ -0,0 +1,266 @@
+use std::collections::VecDeque;
+
+use aoc_common::{parse_number, Answer, Input, ParseError, ParseResult, Solution};
+use tracing::{debug, trace};
+
+#[derive(Clone)]
+pub struct Day11 {
+    monkeys: Vec<Monkey>,
+}
+
+impl Solution for Day11 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let blocks: Vec<Vec<(usize, &str)>> = input.paragraphs().collect();
+
+        // Monkeys throw to each other, and the two most active ones make the answer
+        if blocks.len() < 2 {
+            let (number, line) = blocks.iter().flatten().last().copied().unwrap_or_default();
+            return Err(ParseError::new("Expected at least 2 monkeys", line)
+                .at_line(number.max(1))
+                .in_file(input.name())
+                .into());
+        }
+
+        let monkeys = blocks
+            .iter()
+            .map(|block| {
+                parse_monkey(block, blocks.len()).map_err(|error| error.in_file(input.name()))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day11 { monkeys })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        Ok(self.clone().monkey_business(20, true).into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        Ok(self.clone().monkey_business(10_000, false).into())
+    }
+}
+
+impl Day11 {
+    fn monkey_business(&mut self, rounds: usize, with_relief: bool) -> usize {
+        let monkeys = &mut self.monkeys;
+
+        let lcm = monkeys
+            .iter()
+            .map(|monkey| monkey.test.divisor)
+            .reduce(num::integer::lcm)
+            .expect("Parsing makes sure there are at least 2 monkeys")
+            * 3;
+
+        for round in 1..=rounds {
+            for i in 0..monkeys.len() {
+                let items_count = monkeys[i].items.len();
+
+                for _ in 0..items_count {
+                    let monkey = &mut monkeys[i];
+                    monkey.inspect_next_item(lcm);
+
+                    if with_relief {
+                        monkey.adjust_worry_levels();
+                    }
+
+                    let target_index = monkey.next_target();
+                    let item = monkey.throw();
+                    trace!(
+                        round,
+                        from = i,
+                        to = target_index,
+                        worry = item.worry,
+                        "Monkey threw an item"
+                    );
+                    monkeys[target_index].give(item);
+                }
+            }
+
+            debug!(round, "Round finished");
+        }
+
+        let mut scores: Vec<usize> = monkeys
+            .iter()
+            .map(|monkey| monkey.inspections_count)
+            .collect();
+        scores.sort();
+        scores.reverse();
+
+        scores[0] * scores[1]
+    }
+}
+
+/**
+ * Parses the lines that describe a monkey:
+ *
+ * ```text
+ * Monkey 0:
+ *   Starting items: 79, 98
+ *   Operation: new = old * 19
+ *   Test: divisible by 23
+ *     If true: throw to monkey 2
+ *     If false: throw to monkey 3
+ * ```
+ */
+fn parse_monkey(block: &[(usize, &str)], monkey_count: usize) -> ParseResult<Monkey> {
+    field(block, 0, "Monkey ")?;
+
+    let (number, line, items) = field(block, 1, "Starting items: ")?;
+    let items: VecDeque<Item> = items
+        .split(", ")
+        .map(|worry| {
+            Ok(Item {
+                worry: parse_number(line, worry)?,
+            })
+        })
+        .collect::<ParseResult<_>>()
+        .map_err(|error| error.at_line(number))?;
+
+    let (number, line, operation) = field(block, 2, "Operation: new = old ")?;
+    let operation = match operation.split_once(' ') {
+        Some(("*", "old")) => Operation::Square,
+        Some(("+", value)) => {
+            Operation::Add(parse_number(line, value).map_err(|error| error.at_line(number))?)
+        }
+        Some(("*", value)) => {
+            Operation::Multiply(parse_number(line, value).map_err(|error| error.at_line(number))?)
+        }
+        _ => return Err(ParseError::at("Invalid operation", line, operation).at_line(number)),
+    };
+
+    let (number, line, divisor) = field(block, 3, "Test: divisible by ")?;
+    let divisor = parse_number(line, divisor).map_err(|error| error.at_line(number))?;
+
+    let target = |index, prefix| -> ParseResult<usize> {
+        let (number, line, target) = field(block, index, prefix)?;
+        match parse_number(line, target) {
+            Ok(target) if target < monkey_count => Ok(target),
+            Ok(_) => Err(ParseError::at(
+                format!("There are only {} monkeys", monkey_count),
+                line,
+                target,
+            )),
+            Err(error) => Err(error),
+        }
+        .map_err(|error| error.at_line(number))
+    };
+
+    Ok(Monkey::new(
+        items,
+        operation,
+        Test {
+            divisor,
+            on_true: target(4, "If true: throw to monkey ")?,
+            on_false: target(5, "If false: throw to monkey ")?,
+        },
+    ))
+}
+
+// The line at `index` of a monkey's description, along with its number and what follows `prefix`
+fn field<'a>(
+    block: &[(usize, &'a str)],
+    index: usize,
+    prefix: &str,
+) -> ParseResult<(usize, &'a str, &'a str)> {
+    let Some(&(number, line)) = block.get(index) else {
+        let (number, line) = block.last().copied().unwrap_or_default();
+        return Err(ParseError::new(
+            format!("Missing \"{}\" after this line", prefix.trim()),
+            line,
+        )
+        .at_line(number));
+    };
+
+    match line.trim_start().strip_prefix(prefix) {
+        Some(value) => Ok((number, line, value)),
+        None => {
+            Err(ParseError::new(format!("Expected \"{}\"", prefix.trim()), line).at_line(number))
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Monkey {
+    items: VecDeque<Item>,
+    operation: Operation,
+    test: Test,
+    inspections_count: usize,
+}
+
+impl Monkey {
+    fn new(items: VecDeque<Item>, operation: Operation, test: Test) -> Self {
+        Monkey {
+            items,
+            operation,
+            test,
+            inspections_count: 0,
+        }
+    }
+
+    fn inspect_next_item(&mut self, lcm: usize) {
+        let new_worry = self.operation.apply(self.items[0].worry);
+        self.inspections_count += 1;
+        self.items[0].worry = new_worry % lcm;
+    }
+
+    fn adjust_worry_levels(&mut self) {
+        let new_worry = self.items[0].worry / 3;
+        self.items[0].worry = new_worry;
+    }
+
+    fn next_target(&self) -> usize {
+        self.test.evaluate(self.items[0].worry)
+    }
+
+    fn throw(&mut self) -> Item {
+        self.items
+            .pop_front()
+            .expect("Tried to throw item from empty list")
+    }
+
+    fn give(&mut self, item: Item) {
+        self.items.push_back(item);
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Operation {
+    Add(usize),
+    Multiply(usize),
+    Square,
+}
+
+impl Operation {
+    fn apply(&self, old: usize) -> usize {
+        match self {
+            Operation::Add(value) => old + value,
+            Operation::Multiply(value) => old * value,
+            Operation::Square => old * old,
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Item {
+    worry: usize,
+}
+
+#[derive(Clone)]
+struct Test {
+    divisor: usize,
+    on_true: usize,
+    on_false: usize,
+}
+
+impl Test {
+    fn evaluate(&self, worry: usize) -> usize {
+        if worry.is_multiple_of(self.divisor) {
+            self.on_true
+        } else {
+            self.on_false
+        }
+    }
+}
+
+aoc_common::sample_tests!(Day11);
@@ -0,0 +1,140 @@
+use std::cmp::Ordering;
+
+use anyhow::Context;
+use aoc_common::search::{self, Path};
+use aoc_common::{Answer, Frame, FrameSink, Grid, Input, Part, Solution};
+use tracing::debug;
+
+pub struct Day12 {
+    map: ElevationMap,
+}
+
+impl Solution for Day12 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let squares = Grid::parse(input, |char| match char {
+            'S' | 'E' | 'a'..='z' => Ok(char),
+            char => Err(format!("Invalid elevation {}", char)),
+        })?;
+
+        let origin = squares
+            .position(|&square| square == 'S')
+            .context("Missing origin S")?;
+        let destination = squares
+            .position(|&square| square == 'E')
+            .context("Missing destination E")?;
+        let map = squares.map(|&square| match square {
+            'S' => 'a' as usize,
+            'E' => 'z' as usize,
+            square => square as usize,
+        });
+
+        Ok(Day12 {
+            map: ElevationMap {
+                map,
+                origin,
+                destination,
+            },
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1 = self
+            .map
+            .shortest_path()
+            .context("No path from the origin to the destination")?
+            .cost;
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let part_2 = self
+            .map
+            .shortest_hike()
+            .context("No path from any starting candidate to the destination")?
+            .cost;
+        Ok(part_2.into())
+    }
+
+    // There's nothing to simulate, so each part gets a single picture of its path
+    fn animate(&self, part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let path = match part {
+            Part::One => self.map.shortest_path(),
+            Part::Two => self.map.shortest_hike(),
+        };
+        let path = path.context("No path to the destination")?;
+        let _ = sink.show(self.map.frame(&path));
+
+        Ok(())
+    }
+}
+
+struct ElevationMap {
+    map: Grid<usize>,
+    origin: (usize, usize),
+    destination: (usize, usize),
+}
+
+impl ElevationMap {
+    fn shortest_path(&self) -> Option<Path<(usize, usize)>> {
+        let path = search::bfs(
+            self.origin,
+            |&position| {
+                self.map
+                    .neighbours(position)
+                    .filter(move |&next| self.can_move(position, next))
+            },
+            |&position| position == self.destination,
+        )?;
+
+        debug!(steps = path.cost, "Found a path from the origin");
+        Some(path)
+    }
+
+    // Rather than searching from every square at the lowest elevation, this searches backwards from
+    // the destination until it finds the closest one
+    fn shortest_hike(&self) -> Option<Path<(usize, usize)>> {
+        let mut path = search::bfs(
+            self.destination,
+            |&position| {
+                self.map
+                    .neighbours(position)
+                    .filter(move |&previous| self.can_move(previous, position))
+            },
+            |&position| self.map[position] == 'a' as usize,
+        )?;
+
+        path.nodes.reverse();
+        debug!(steps = path.cost, start = ?path.nodes[0], "Found the shortest hike");
+        Some(path)
+    }
+
+    fn can_move(&self, from: (usize, usize), to: (usize, usize)) -> bool {
+        self.map[to] <= self.map[from] + 1
+    }
+
+    // The elevation of each square, with the path drawn over it like in the puzzle: an arrow on each
+    // step pointing to the next one, and E at the destination. The arrows aren't ^v<> as in the
+    // puzzle so they can't be mistaken for an elevation of v.
+    fn frame(&self, path: &Path<(usize, usize)>) -> Frame {
+        let mut cells = self
+            .map
+            .map(|&elevation| char::from_u32(elevation as u32).unwrap_or('?'));
+
+        for step in path.nodes.windows(2) {
+            let ((from_x, from_y), (to_x, to_y)) = (step[0], step[1]);
+
+            cells[step[0]] = match (to_x.cmp(&from_x), to_y.cmp(&from_y)) {
+                (Ordering::Greater, _) => '→',
+                (Ordering::Less, _) => '←',
+                (_, Ordering::Greater) => '↓',
+                _ => '↑',
+            };
+        }
+        cells[self.destination] = 'E';
+
+        Frame::new(format!("{} steps", path.cost), cells)
+    }
+}
+
+aoc_common::sample_tests!(Day12);
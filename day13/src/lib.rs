@@ -0,0 +1,184 @@
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+use aoc_common::{Answer, Input, ParseError, Solution};
+use serde_json::Value;
+
+pub struct Day13 {
+    packets: Vec<Packet>,
+}
+
+impl Solution for Day13 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let mut packets: Vec<Packet> = vec![];
+
+        for pair in input.paragraphs() {
+            if pair.len() != 2 {
+                let (number, line) = pair[0];
+                let error = ParseError::new("Expected a pair of packets, one per line", line);
+                return Err(input.locate(error, number).into());
+            }
+
+            for (number, line) in pair {
+                packets.push(line.parse().map_err(|error| input.locate(error, number))?);
+            }
+        }
+
+        Ok(Day13 { packets })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let part_1: usize = self
+            .packets
+            .chunks_exact(2)
+            .enumerate()
+            .filter_map(|(i, pair)| if pair[0] < pair[1] { Some(i + 1) } else { None })
+            .sum();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let mut packets = self.packets.clone();
+
+        let divider_1: Packet = "[[2]]".parse().unwrap();
+        let divider_2: Packet = "[[6]]".parse().unwrap();
+        packets.push(divider_1.clone());
+        packets.push(divider_2.clone());
+
+        packets.sort();
+
+        let divider_1_index = packets
+            .iter()
+            .position(|packet| packet == &divider_1)
+            .unwrap();
+        let divider_2_index = packets
+            .iter()
+            .position(|packet| packet == &divider_2)
+            .unwrap();
+
+        let part_2 = (divider_1_index + 1) * (divider_2_index + 1);
+        Ok(part_2.into())
+    }
+}
+
+#[derive(PartialEq, Eq, Clone)]
+struct Packet {
+    data: Data,
+}
+
+impl FromStr for Packet {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let data: Data = s.parse()?;
+
+        match data {
+            Data::List(_) => Ok(Packet { data }),
+            Data::Value(_) => Err(ParseError::new(
+                "Packets require a list as top-level data",
+                s,
+            )),
+        }
+    }
+}
+
+// `Data` returns `None` when two lists can't be told apart, which is not the same as `Ord::cmp`
+// (that one panics), so the implementations are intentionally not defined in terms of each other.
+#[allow(clippy::non_canonical_partial_ord_impl)]
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.data.partial_cmp(&other.data)
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.data
+            .partial_cmp(&other.data)
+            .expect("Packets should be fully orderable")
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Data {
+    Value(usize),
+    List(Vec<Data>),
+}
+
+impl PartialOrd for Data {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        match (self, other) {
+            (Data::Value(left), Data::Value(right)) => left.partial_cmp(right),
+            (Data::Value(left), Data::List(_)) => {
+                Data::List(vec![Data::Value(*left)]).partial_cmp(other)
+            }
+            (Data::List(_), Data::Value(right)) => {
+                self.partial_cmp(&Data::List(vec![Data::Value(*right)]))
+            }
+            (Data::List(left), Data::List(right)) => {
+                for i in 0..left.len() {
+                    let left = &left[i];
+                    let right = right.get(i);
+
+                    match right {
+                        Some(data) => match left.partial_cmp(data) {
+                            ordering @ Some(Ordering::Less)
+                            | ordering @ Some(Ordering::Greater) => return ordering,
+                            _ => {}
+                        },
+                        None => return Some(Ordering::Greater),
+                    }
+                }
+
+                if left.len() == right.len() {
+                    None
+                } else {
+                    Some(Ordering::Less)
+                }
+            }
+        }
+    }
+}
+
+impl TryFrom<&Value> for Data {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        match value {
+            Value::Number(number) => Ok(Data::Value(
+                number
+                    .as_u64()
+                    .ok_or_else(|| format!("Invalid integer {}", number))? as usize,
+            )),
+            Value::Array(list) => Ok(Data::List(
+                list.iter()
+                    .map(|value| value.try_into())
+                    .collect::<Result<Vec<Data>, _>>()?,
+            )),
+            other => Err(format!("Unexpected value {}", other)),
+        }
+    }
+}
+
+impl FromStr for Data {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let as_json: Value = serde_json::from_str(s).map_err(|error| {
+            // serde_json counts columns from 1, and includes them in its message
+            let column = error.column().saturating_sub(1);
+            let message = error.to_string();
+            let message = message.split(" at line ").next().unwrap_or_default();
+            ParseError::with_columns(
+                format!("Invalid packet: {}", message),
+                s,
+                column..column + 1,
+            )
+        })?;
+        (&as_json)
+            .try_into()
+            .map_err(|message: String| ParseError::new(message, s))
+    }
+}
+
+aoc_common::sample_tests!(Day13);
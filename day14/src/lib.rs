@@ -0,0 +1,216 @@
+use std::ops::{ControlFlow, RangeInclusive};
+
+use anyhow::Context;
+use aoc_common::{parse_number, Answer, Frame, FrameSink, Grid, Input, ParseError, Part, Solution};
+use tracing::debug;
+
+const SOURCE: (usize, usize) = (500, 0);
+
+pub struct Day14 {
+    map: Map,
+}
+
+impl Solution for Day14 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let rock_coordinates: Vec<Vec<(usize, usize)>> = input.parse_lines_with(|line| {
+            line.split(" -> ")
+                .map(|coordinates| {
+                    let (x, y) = coordinates.split_once(',').ok_or_else(|| {
+                        ParseError::at("Expected coordinates like 498,4", line, coordinates)
+                    })?;
+                    Ok((parse_number(line, x)?, parse_number(line, y)?))
+                })
+                .collect()
+        })?;
+
+        let rocks = rock_coordinates.iter().flatten();
+        let lowest_rock = rocks
+            .clone()
+            .map(|&(_, y)| y)
+            .max()
+            .context("Can't build map without any rock")?;
+        let rightmost_rock = rocks.map(|&(x, _)| x).max().unwrap_or_default();
+        let mut map = Map::new(rightmost_rock + 1, lowest_rock + 2);
+
+        for structure in &rock_coordinates {
+            for window in structure.windows(2) {
+                let from = window[0];
+                let to = window[1];
+                map.cave[from] = Tile::Rock;
+
+                let mut next = from;
+                while next != to {
+                    if to.0 == from.0 && to.1 > from.1 {
+                        next.1 += 1;
+                    } else if to.0 == from.0 && to.1 < from.1 {
+                        next.1 -= 1;
+                    } else if to.1 == from.1 && to.0 > from.0 {
+                        next.0 += 1;
+                    } else {
+                        next.0 -= 1;
+                    }
+
+                    map.cave[next] = Tile::Rock;
+                }
+            }
+        }
+
+        Ok(Day14 { map })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let mut map = self.map.clone();
+        let part_1 = map.simulate_sand(false, |_, _| ControlFlow::Continue(()));
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let mut map = self.map.clone();
+        let part_2 = map.simulate_sand(true, |_, _| ControlFlow::Continue(()));
+        Ok(part_2.into())
+    }
+
+    fn animate(&self, part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        let has_floor = part == Part::Two;
+        let mut map = self.map.clone();
+        let columns = map.columns_in_view(has_floor);
+
+        map.simulate_sand(has_floor, |map, sand| {
+            sink.show(map.frame(columns.clone(), has_floor, sand))
+        });
+
+        Ok(())
+    }
+}
+
+#[derive(Copy, Clone, PartialEq, Eq)]
+enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl Tile {
+    fn symbol(self) -> char {
+        match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        }
+    }
+}
+
+#[derive(Clone)]
+struct Map {
+    cave: Grid<Tile>,
+    bottom: usize,
+}
+
+impl Map {
+    // Sand that piles up from the source to the floor spreads at most one column per row to each
+    // side, so the cave only needs to be that wide (or as wide as the rock goes)
+    fn new(width: usize, bottom: usize) -> Self {
+        let width = width.max(SOURCE.0 + bottom + 1);
+
+        Map {
+            cave: Grid::new(width, bottom, Tile::Air),
+            bottom,
+        }
+    }
+
+    // Without a floor, sand that goes below the lowest rock falls into the abyss forever, which
+    // ends the simulation. With a floor, it ends when sand piles up all the way to the source.
+    // `observe` is called each time a unit of sand comes to rest.
+    fn simulate_sand(
+        &mut self,
+        has_floor: bool,
+        mut observe: impl FnMut(&Map, usize) -> ControlFlow<()>,
+    ) -> usize {
+        let mut sand = 0;
+
+        'grain_of_sand: loop {
+            let mut sand_position = SOURCE;
+
+            loop {
+                if !has_floor && sand_position.1 == self.bottom - 1 {
+                    break 'grain_of_sand;
+                }
+
+                if self.is_free((sand_position.0, sand_position.1 + 1)) {
+                    sand_position.1 += 1;
+                } else if self.is_free((sand_position.0 - 1, sand_position.1 + 1)) {
+                    sand_position.0 -= 1;
+                    sand_position.1 += 1;
+                } else if self.is_free((sand_position.0 + 1, sand_position.1 + 1)) {
+                    sand_position.0 += 1;
+                    sand_position.1 += 1;
+                } else {
+                    self.cave[sand_position] = Tile::Sand;
+                    sand += 1;
+                    debug!(
+                        x = sand_position.0,
+                        y = sand_position.1,
+                        sand,
+                        "Sand came to rest"
+                    );
+
+                    if observe(self, sand).is_break() || sand_position == SOURCE {
+                        break 'grain_of_sand;
+                    }
+
+                    continue 'grain_of_sand;
+                }
+            }
+        }
+
+        sand
+    }
+
+    fn is_free(&self, coordinates: (usize, usize)) -> bool {
+        self.cave.get(coordinates) == Some(&Tile::Air)
+    }
+
+    // Columns where sand can end up, either resting or falling off the side of the rock
+    fn columns_in_view(&self, has_floor: bool) -> RangeInclusive<usize> {
+        let rock_columns = self
+            .cave
+            .iter()
+            .filter(|&(_, &tile)| tile == Tile::Rock)
+            .map(|((x, _), _)| x);
+        let (mut left, mut right) = rock_columns.fold((SOURCE.0, SOURCE.0), |(left, right), x| {
+            (left.min(x), right.max(x))
+        });
+
+        if has_floor {
+            left = left.min(SOURCE.0.saturating_sub(self.bottom));
+            right = right.max(SOURCE.0 + self.bottom);
+        }
+
+        left.saturating_sub(1)..=(right + 1).min(self.cave.width() - 1)
+    }
+
+    fn frame(&self, columns: RangeInclusive<usize>, has_floor: bool, sand: usize) -> Frame {
+        let left = *columns.start();
+        let height = if has_floor {
+            self.bottom + 1
+        } else {
+            self.bottom
+        };
+
+        let cells = Grid::from_fn(columns.count(), height, |(x, y)| {
+            let position = (x + left, y);
+
+            match self.cave.get(position) {
+                Some(Tile::Air) if position == SOURCE => '+',
+                Some(tile) => tile.symbol(),
+                // The floor
+                None => '#',
+            }
+        });
+
+        Frame::new(format!("{} units of sand", sand), cells)
+    }
+}
+
+aoc_common::sample_tests!(Day14);
@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+use std::str::FromStr;
+
+use anyhow::Context;
+use aoc_common::{parse_number, Answer, Input, ParseError, Point, Solution};
+use regex::Regex;
+use tracing::debug;
+
+// Size of the area the missing beacon is in, for the example of the puzzle and the real input
+const SAMPLE_SIZE: isize = 20;
+const REAL_SIZE: isize = 4_000_000;
+const TUNING_MULTIPLIER: isize = 4_000_000;
+
+pub struct Day15 {
+    circles: Vec<ManhattanCircle>,
+    /// The missing beacon has both coordinates between 0 and this
+    size: isize,
+    /// Where part 1 counts the positions that can't have a beacon, halfway through the area
+    row: isize,
+}
+
+impl Solution for Day15 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let circles: Vec<ManhattanCircle> = input.parse_lines()?;
+
+        if circles.is_empty() {
+            return Err(ParseError::new("Expected at least one sensor", "")
+                .in_file(input.name())
+                .into());
+        }
+
+        // The example is checked on a smaller area than the real input, and all its sensors fit in it
+        let size = if circles.iter().all(|circle| {
+            (0..=SAMPLE_SIZE).contains(&circle.center.x)
+                && (0..=SAMPLE_SIZE).contains(&circle.center.y)
+        }) {
+            SAMPLE_SIZE
+        } else {
+            REAL_SIZE
+        };
+
+        Ok(Day15 {
+            circles,
+            size,
+            row: size / 2,
+        })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let circles = &self.circles;
+
+        const NOT_EMPTY: &str = "Parsing makes sure there's at least one sensor";
+        let from_x = circles
+            .iter()
+            .map(|circle| circle.left())
+            .min()
+            .expect(NOT_EMPTY);
+        let to_x = circles
+            .iter()
+            .map(|circle| circle.right())
+            .max()
+            .expect(NOT_EMPTY);
+        let beacons: HashSet<Point> = circles.iter().map(|circle| circle.beacon).collect();
+
+        let part_1 = (from_x..=to_x)
+            .map(|x| Point::new(x, self.row))
+            .filter(|point| !beacons.contains(point))
+            .filter(|&point| circles.iter().any(|circle| circle.contains(point)))
+            .count();
+        Ok(part_1.into())
+    }
+
+    fn part2(&self) -> anyhow::Result<Answer> {
+        let circles = &self.circles;
+
+        let missing_beacon = circles
+            .iter()
+            .flat_map(|circle| circle.just_outside_of_range())
+            .find(|&point| {
+                (0..=self.size).contains(&point.x)
+                    && (0..=self.size).contains(&point.y)
+                    && !circles.iter().any(|circle| circle.contains(point))
+            })
+            .context("Unable to find the missing beacon")?;
+        debug!(
+            x = missing_beacon.x,
+            y = missing_beacon.y,
+            "Found the missing beacon"
+        );
+
+        let part_2 = TUNING_MULTIPLIER * missing_beacon.x + missing_beacon.y;
+        Ok(part_2.into())
+    }
+}
+
+#[derive(Debug)]
+struct ManhattanCircle {
+    center: Point,
+    beacon: Point,
+    radius: usize,
+}
+
+impl ManhattanCircle {
+    fn new(center: Point, beacon: Point) -> Self {
+        ManhattanCircle {
+            center,
+            beacon,
+            radius: center.manhattan(beacon),
+        }
+    }
+
+    fn left(&self) -> isize {
+        self.center.x - self.radius as isize
+    }
+
+    fn right(&self) -> isize {
+        self.center.x + self.radius as isize
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.center.manhattan(point) <= self.radius
+    }
+
+    fn just_outside_of_range(&self) -> impl Iterator<Item = Point> + '_ {
+        let distance = self.radius as isize + 1;
+        ((self.center.y - distance)..=(self.center.y + distance)).flat_map(move |y| {
+            let y_distance = (self.center.y - y).abs();
+
+            [
+                Point::new(self.center.x - distance + y_distance, y),
+                Point::new(self.center.x + distance - y_distance, y),
+            ]
+        })
+    }
+}
+
+impl FromStr for ManhattanCircle {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let re =
+            Regex::new(r"Sensor at x=(\d+), y=(\d+): closest beacon is at x=(-?\d+), y=(-?\d+)")
+                .unwrap();
+        let captures = re.captures(s).ok_or_else(|| {
+            ParseError::new(
+                "Expected Sensor at x=.., y=..: closest beacon is at x=.., y=..",
+                s,
+            )
+        })?;
+        let x: isize = parse_number(s, &captures[1])?;
+        let y: isize = parse_number(s, &captures[2])?;
+        let beacon_x: isize = parse_number(s, &captures[3])?;
+        let beacon_y: isize = parse_number(s, &captures[4])?;
+
+        Ok(ManhattanCircle::new(
+            Point::new(x, y),
+            Point::new(beacon_x, beacon_y),
+        ))
+    }
+}
+
+aoc_common::sample_tests!(Day15);
@@ -0,0 +1,279 @@
+use std::ops::ControlFlow;
+
+use anyhow::Context;
+use aoc_common::{
+    Answer, Direction, Frame, FrameSink, Grid, Input, ParseError, Part, Solution, Unsolved,
+};
+use tracing::debug;
+
+pub struct Day17 {
+    directions: Vec<Direction>,
+}
+
+impl Solution for Day17 {
+    fn parse(input: &Input) -> anyhow::Result<Self> {
+        let input = &input.trim_trailing_whitespace();
+        let line = input.lines().next().context("Didn't find any lines")?;
+        let directions = line
+            .chars()
+            .enumerate()
+            .map(|(column, c)| {
+                jet_direction(c).ok_or_else(|| {
+                    let error = ParseError::with_columns(
+                        format!("Invalid direction {}", c),
+                        line,
+                        column..column + 1,
+                    );
+                    input.locate(error, 1)
+                })
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(Day17 { directions })
+    }
+
+    fn part1(&self) -> anyhow::Result<Answer> {
+        let shapes = vec![Shape::Dash, Shape::Plus, Shape::J, Shape::I, Shape::O];
+
+        let mut board = Board::new(shapes, self.directions.clone());
+        board.simulate(2022, |_, _, _| ControlFlow::Continue(()));
+        let part_1 = board.tower_height();
+        Ok(part_1.into())
+    }
+
+    fn animate(&self, part: Part, sink: &mut dyn FrameSink) -> anyhow::Result<()> {
+        if part == Part::Two {
+            return Err(Unsolved(Part::Two).into());
+        }
+
+        let shapes = vec![Shape::Dash, Shape::Plus, Shape::J, Shape::I, Shape::O];
+
+        let mut board = Board::new(shapes, self.directions.clone());
+        board.simulate(2022, |board, number, falling| {
+            sink.show(board.frame(number, falling))
+        });
+
+        Ok(())
+    }
+}
+
+const WIDTH: usize = 7;
+// Rows at the top of the chamber shown in each frame of the animation
+const VIEW_HEIGHT: usize = 30;
+
+struct Board {
+    // Rows go from the bottom up, so the chamber grows by pushing rows as the tower gets taller
+    chamber: Grid<bool>,
+    directions: Vec<Direction>,
+    shapes: Vec<Shape>,
+}
+
+impl Board {
+    fn new(shapes: Vec<Shape>, directions: Vec<Direction>) -> Self {
+        Board {
+            chamber: Grid::new(WIDTH, 0, false),
+            shapes,
+            directions,
+        }
+    }
+
+    // `observe` is called after each time a piece is pushed and falls, with the number of the
+    // piece and where it is, unless it came to rest
+    fn simulate(
+        &mut self,
+        number_of_pieces: usize,
+        mut observe: impl FnMut(&Board, usize, Option<&[(usize, usize)]>) -> ControlFlow<()>,
+    ) {
+        let mut directions_iter = self.directions.iter().cycle();
+        let shapes_iter = self.shapes.iter().cycle();
+
+        for (number, shape) in (1..).zip(shapes_iter.take(number_of_pieces)) {
+            let mut has_fallen = false;
+            let mut left: usize = 2;
+            let mut bottom = self.tower_height() + 3;
+
+            while !has_fallen {
+                let direction = directions_iter.next().unwrap();
+
+                if let Some(pushed) = left.checked_add_signed(direction.delta().x) {
+                    if pushed + shape.width() <= WIDTH && self.can_move(shape, pushed, bottom) {
+                        left = pushed;
+                    }
+                }
+
+                if bottom > 0 && self.can_move(shape, left, bottom - 1) {
+                    bottom -= 1;
+                } else {
+                    for position in shape.from_left_and_bottom(left, bottom) {
+                        while self.chamber.height() <= position.1 {
+                            self.chamber.push_row([false; WIDTH]);
+                        }
+
+                        self.chamber[position] = true;
+                    }
+
+                    has_fallen = true;
+                    debug!(
+                        rock = number,
+                        left,
+                        bottom,
+                        height = self.tower_height(),
+                        "Rock came to rest"
+                    );
+                }
+
+                let falling = (!has_fallen).then(|| shape.from_left_and_bottom(left, bottom));
+                if observe(self, number, falling.as_deref()).is_break() {
+                    return;
+                }
+            }
+        }
+    }
+
+    fn can_move(&self, shape: &Shape, left: usize, bottom: usize) -> bool {
+        shape
+            .from_left_and_bottom(left, bottom)
+            .into_iter()
+            .all(|position| self.chamber.get(position) != Some(&true))
+    }
+
+    // Rows are only added to hold rock, so the topmost one always has some
+    fn tower_height(&self) -> usize {
+        self.chamber.height()
+    }
+
+    // The top of the chamber, drawn the same way as in the puzzle: with walls on the sides, the
+    // floor once it's in view, and the falling piece with @
+    fn frame(&self, number: usize, falling: Option<&[(usize, usize)]>) -> Frame {
+        let falling = falling.unwrap_or_default();
+        let top = falling
+            .iter()
+            .map(|&(_, y)| y + 1)
+            .chain([self.tower_height()])
+            .max()
+            .unwrap_or_default();
+        let height = top.min(VIEW_HEIGHT);
+        let has_floor = height == top;
+
+        let rows = height + usize::from(has_floor);
+        let cells = Grid::from_fn(WIDTH + 2, rows, |(x, row)| {
+            if has_floor && row == height {
+                return if x == 0 || x == WIDTH + 1 { '+' } else { '-' };
+            }
+
+            if x == 0 || x == WIDTH + 1 {
+                return '|';
+            }
+
+            let position = (x - 1, top - 1 - row);
+
+            if falling.contains(&position) {
+                '@'
+            } else if self.chamber.get(position) == Some(&true) {
+                '#'
+            } else {
+                '.'
+            }
+        });
+
+        let caption = format!("Rock {}, tower height {}", number, self.tower_height());
+        Frame::new(caption, cells)
+    }
+}
+
+// Jets of gas only ever push left or right
+fn jet_direction(c: char) -> Option<Direction> {
+    match c {
+        '<' => Some(Direction::Left),
+        '>' => Some(Direction::Right),
+        _ => None,
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Shape {
+    /**
+     * ####
+     */
+    Dash,
+    /**
+     * .#.
+     * ###
+     * .#.
+     */
+    Plus,
+    /**
+     * ..#
+     * ..#
+     * ###
+     */
+    J,
+    /**
+     * #
+     * #
+     * #
+     * #
+     */
+    I,
+    /**
+     * ##
+     * ##
+     */
+    O,
+}
+
+impl Shape {
+    fn width(&self) -> usize {
+        use Shape::*;
+
+        match self {
+            Dash => 4,
+            Plus => 3,
+            J => 3,
+            I => 1,
+            O => 2,
+        }
+    }
+
+    #[allow(clippy::wrong_self_convention)]
+    fn from_left_and_bottom(&self, left: usize, bottom: usize) -> Vec<(usize, usize)> {
+        use Shape::*;
+
+        match self {
+            Dash => vec![
+                (left, bottom),
+                (left + 1, bottom),
+                (left + 2, bottom),
+                (left + 3, bottom),
+            ],
+            Plus => vec![
+                (left + 1, bottom),
+                (left, bottom + 1),
+                (left + 1, bottom + 1),
+                (left + 2, bottom + 1),
+                (left + 1, bottom + 2),
+            ],
+            J => vec![
+                (left, bottom),
+                (left + 1, bottom),
+                (left + 2, bottom),
+                (left + 2, bottom + 1),
+                (left + 2, bottom + 2),
+            ],
+            I => vec![
+                (left, bottom),
+                (left, bottom + 1),
+                (left, bottom + 2),
+                (left, bottom + 3),
+            ],
+            O => vec![
+                (left, bottom),
+                (left, bottom + 1),
+                (left + 1, bottom),
+                (left + 1, bottom + 1),
+            ],
+        }
+    }
+}
+
+aoc_common::sample_tests!(Day17);